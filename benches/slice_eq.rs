@@ -0,0 +1,66 @@
+//! Compares equality and ordering of aligned slices against the same operations on plain byte slices.
+//!
+//! Run with `cargo bench --bench slice_eq`.
+use aligners::{alignment, AlignedBytes};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LARGE_SIZE: usize = 16 * 1024 * 1024;
+const LARGE_ITERATIONS: u32 = 50;
+// Small enough to stay in L1 between iterations.
+const CACHED_SIZE: usize = 16 * 1024;
+const CACHED_ITERATIONS: u32 = 100_000;
+
+fn measure<F: FnMut() -> bool>(iterations: u32, mut f: F) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        black_box(f());
+    }
+
+    start.elapsed() / iterations
+}
+
+fn bench(
+    name: &str,
+    iterations: u32,
+    bytes: &AlignedBytes<alignment::TwoTo<6>>,
+    other: &AlignedBytes<alignment::TwoTo<6>>,
+) {
+    let slice: &[u8] = bytes;
+    let other_slice: &[u8] = other;
+
+    let aligned_eq = measure(iterations, || black_box(bytes) == black_box(other));
+    let bytes_eq = measure(iterations, || black_box(slice) == black_box(other_slice));
+    let aligned_cmp = measure(iterations, || {
+        black_box(bytes).cmp(black_box(other)).is_eq()
+    });
+    let bytes_cmp = measure(iterations, || {
+        black_box(slice).cmp(black_box(other_slice)).is_eq()
+    });
+
+    println!("{name}:");
+    println!("  eq   AlignedSlice {aligned_eq:>12?}   [u8] {bytes_eq:>12?}");
+    println!("  cmp  AlignedSlice {aligned_cmp:>12?}   [u8] {bytes_cmp:>12?}");
+}
+
+fn bench_size(name: &str, size: usize, iterations: u32) {
+    let bytes: AlignedBytes<alignment::TwoTo<6>> =
+        AlignedBytes::new_initialize(size, |i| (i * 7) as u8);
+    let equal = bytes.clone();
+    let mut near_equal = bytes.clone();
+    near_equal[size - 1] ^= 1;
+
+    bench(&format!("{name}, equal"), iterations, &bytes, &equal);
+    bench(
+        &format!("{name}, differing on last byte"),
+        iterations,
+        &bytes,
+        &near_equal,
+    );
+}
+
+fn main() {
+    bench_size("16 MiB", LARGE_SIZE, LARGE_ITERATIONS);
+    bench_size("16 KiB", CACHED_SIZE, CACHED_ITERATIONS);
+}
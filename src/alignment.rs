@@ -15,15 +15,47 @@ pub unsafe trait Alignment {
     fn size() -> usize;
 }
 
+/// Trait for alignment types whose size is known at compile time.
+///
+/// This allows using the alignment in const contexts, for example as an array length.
+/// Alignments that can only be determined at runtime, like [`Page`], do not implement it.
+///
+/// # Safety
+/// In addition to the requirements of [`Alignment`], [`ALIGNMENT`](`ConstAlignment::ALIGNMENT`)
+/// *MUST* be equal to the value returned by [`size`](`Alignment::size`).
+///
+/// # Examples
+/// ```rust
+/// use aligners::alignment::{self, ConstAlignment};
+///
+/// const BLOCK: usize = alignment::TwoTo::<4>::ALIGNMENT;
+/// let buffer = [0_u8; BLOCK];
+///
+/// assert_eq!(16, buffer.len());
+/// ```
+pub unsafe trait ConstAlignment: Alignment {
+    /// Size of the alignment.
+    const ALIGNMENT: usize;
+}
+
 /// Alignment to $2^N$. All acceptable alignments can be derived
 /// from this alignment, for example 64-byte alignment is simply [`TwoTo<6>`].
 ///
+/// $2^N$ must fit in a [`usize`], so `N` must be less than [`usize::BITS`]. This is checked
+/// at compile time, so using a larger value is a hard error as soon as the alignment's size is needed.
+///
 /// # Examples
 /// ```rust
 /// use aligners::alignment::{self, Alignment};
 ///
 /// assert_eq!(64, alignment::TwoTo::<6>::size());
 /// ```
+///
+/// ```compile_fail
+/// use aligners::alignment::{self, Alignment};
+///
+/// let _ = alignment::TwoTo::<128>::size();
+/// ```
 #[derive(Debug)]
 pub enum TwoTo<const N: u32> {}
 
@@ -72,10 +104,65 @@ pub type Eight = TwoTo<3>;
 unsafe impl<const N: u32> Alignment for TwoTo<N> {
     #[inline(always)]
     fn size() -> usize {
-        2usize.pow(N)
+        Self::ALIGNMENT
+    }
+}
+
+// SAFETY:
+// Same value as `size`.
+unsafe impl<const N: u32> ConstAlignment for TwoTo<N> {
+    const ALIGNMENT: usize = {
+        assert!(
+            N < usize::BITS,
+            "exponent of TwoTo must be less than usize::BITS, 2^N does not fit in a usize"
+        );
+        1 << N
+    };
+}
+
+/// Alignment to exactly `N` bytes, for when thinking in exponents is inconvenient.
+/// For example 64-byte alignment is [`Align<64>`], equivalent to [`TwoTo<6>`].
+///
+/// `N` must be a power of two. This is checked at compile time, so using a different value
+/// is a hard error as soon as the alignment's size is needed.
+///
+/// # Examples
+/// ```rust
+/// use aligners::alignment::{self, Alignment};
+///
+/// assert_eq!(64, alignment::Align::<64>::size());
+/// ```
+///
+/// ```compile_fail
+/// use aligners::alignment::{self, Alignment};
+///
+/// let _ = alignment::Align::<48>::size();
+/// ```
+#[derive(Debug)]
+pub enum Align<const N: usize> {}
+
+impl<const N: usize> Align<N> {
+    const SIZE: usize = {
+        assert!(N.is_power_of_two(), "alignment must be a power of two");
+        N
+    };
+}
+
+// SAFETY:
+// N is asserted to be a power of two at compile time in `SIZE`.
+unsafe impl<const N: usize> Alignment for Align<N> {
+    #[inline(always)]
+    fn size() -> usize {
+        Self::SIZE
     }
 }
 
+// SAFETY:
+// Same value as `size`.
+unsafe impl<const N: usize> ConstAlignment for Align<N> {
+    const ALIGNMENT: usize = Self::SIZE;
+}
+
 cfg_if! {
     if #[cfg(doc)] {
         #[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
@@ -91,6 +178,8 @@ cfg_if! {
     }
 }
 
+mod cache_line;
+pub use cache_line::*;
 mod page;
 pub use page::*;
 mod multiple;
@@ -0,0 +1,31 @@
+use super::{Alignment, ConstAlignment};
+
+/// Alignment to a cache line boundary.
+///
+/// The size is 64 bytes, which is the cache line size of most x86 and ARM CPUs.
+/// Some architectures have larger cache lines, for example 128 bytes on Apple Silicon
+/// or some POWER CPUs. If you need such a guarantee, use [`TwoTo<7>`](`super::TwoTo`) instead.
+///
+/// # Examples
+/// ```rust
+/// use aligners::alignment::{self, Alignment};
+///
+/// assert_eq!(64, alignment::CacheLine::size());
+/// ```
+#[derive(Debug)]
+pub enum CacheLine {}
+
+// SAFETY:
+// Always returning a const value that is a power of two.
+unsafe impl Alignment for CacheLine {
+    #[inline(always)]
+    fn size() -> usize {
+        Self::ALIGNMENT
+    }
+}
+
+// SAFETY:
+// Same value as `size`.
+unsafe impl ConstAlignment for CacheLine {
+    const ALIGNMENT: usize = 64;
+}
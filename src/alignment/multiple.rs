@@ -1,4 +1,4 @@
-use super::Alignment;
+use super::{Alignment, ConstAlignment};
 
 /// Alignment to twice the alignment of `A`.
 ///
@@ -23,3 +23,9 @@ unsafe impl<A: Alignment> Alignment for Twice<A> {
         A::size() * 2
     }
 }
+
+// SAFETY:
+// Same value as `size`, as long as `A`'s impl is safe.
+unsafe impl<A: ConstAlignment> ConstAlignment for Twice<A> {
+    const ALIGNMENT: usize = A::ALIGNMENT * 2;
+}
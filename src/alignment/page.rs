@@ -1,4 +1,4 @@
-use super::Alignment;
+use super::{Alignment, ConstAlignment};
 
 /// Alignment to page boundary.
 ///
@@ -44,3 +44,63 @@ unsafe impl Alignment for Page {
         *PAGE_SIZE
     }
 }
+
+/// Alignment to a 2 MiB huge page boundary.
+///
+/// This is the size of a huge page on x86-64 and the most common size of transparent huge pages.
+/// Note that this only guarantees the alignment of the bytes, whether they are
+/// actually backed by huge pages depends on the OS.
+///
+/// # Examples
+/// ```rust
+/// use aligners::alignment::{self, Alignment};
+///
+/// assert_eq!(2 * 1024 * 1024, alignment::HugePage2MiB::size());
+/// ```
+#[derive(Debug)]
+pub enum HugePage2MiB {}
+
+// SAFETY:
+// Always returning a const value that is a power of two.
+unsafe impl Alignment for HugePage2MiB {
+    #[inline(always)]
+    fn size() -> usize {
+        Self::ALIGNMENT
+    }
+}
+
+// SAFETY:
+// Same value as `size`.
+unsafe impl ConstAlignment for HugePage2MiB {
+    const ALIGNMENT: usize = 2 * 1024 * 1024;
+}
+
+/// Alignment to a 1 GiB huge page boundary.
+///
+/// This is the size of a gigantic page on x86-64.
+/// Note that this only guarantees the alignment of the bytes, whether they are
+/// actually backed by huge pages depends on the OS.
+///
+/// # Examples
+/// ```rust
+/// use aligners::alignment::{self, Alignment};
+///
+/// assert_eq!(1024 * 1024 * 1024, alignment::HugePage1GiB::size());
+/// ```
+#[derive(Debug)]
+pub enum HugePage1GiB {}
+
+// SAFETY:
+// Always returning a const value that is a power of two.
+unsafe impl Alignment for HugePage1GiB {
+    #[inline(always)]
+    fn size() -> usize {
+        Self::ALIGNMENT
+    }
+}
+
+// SAFETY:
+// Same value as `size`.
+unsafe impl ConstAlignment for HugePage1GiB {
+    const ALIGNMENT: usize = 1024 * 1024 * 1024;
+}
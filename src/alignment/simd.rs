@@ -14,12 +14,18 @@ use cfg_if::cfg_if;
 ///
 /// | CPU feature     | Alignment (bytes) | Required `target_feature`      |
 /// |:----------------|------------------:|-------------------------------:|
-/// | AVX512          | 64                | `avx512f`                      |
+/// | AVX512          | 64                | `avx512f`, requires the `avx512` crate feature |
 /// | AVX             | 32                | any of `avx`, `avx2`           |
 /// | SSE             | 16                | any of `sse`, `sse2`, `sse3`, <br/> `sse4.1`, `sse4.2`, `ssse3` |
+/// | NEON            | 16                | `neon` (ARM/AArch64 only)      |
+/// | WebAssembly SIMD | 16               | `simd128` (wasm32 only)        |
 ///
 /// If the target does not support any of these extensions, the compilation will fail.
 /// In that case you need to disable the `simd` feature.
+///
+/// The 64-byte AVX512 alignment is opt-in through the `avx512` crate feature.
+/// Without it the `avx512f` target feature is ignored and the size is capped at 32 bytes on AVX targets.
+/// Enabling `avx512f` may require a nightly toolchain, depending on the compiler version.
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
 pub enum SimdBlock {}
@@ -32,16 +38,31 @@ unsafe impl Alignment for SimdBlock {
         cfg_if! {
             if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
                 cfg_if! {
-                    if #[cfg(target_feature = "avx512f")] {
+                    if #[cfg(all(feature = "avx512", target_feature = "avx512f"))] {
                         64
                     }
                     else if #[cfg(target_feature = "avx")] {
                         32
                     }
-                    else if #[cfg(target_feature = "sse")] {
+                    else if #[cfg(any(
+                        target_feature = "sse",
+                        target_feature = "sse2",
+                        target_feature = "sse3",
+                        target_feature = "sse4.1",
+                        target_feature = "sse4.2",
+                        target_feature = "ssse3"
+                    ))] {
                         16
                     }
+                    else {
+                        compile_error!("No SSE-family target feature is enabled for this x86 target. Enable at least `sse` or disable the default `simd` feature.");
+                        unreachable!();
+                    }
                 }
+            } else if #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))] {
+                16
+            } else if #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))] {
+                16
             } else if #[cfg(doc)] {
                 32
             }
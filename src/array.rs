@@ -0,0 +1,349 @@
+use crate::alignment::Alignment;
+use std::alloc::Layout;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// Elements of type `T` aligned to a boundary represented by `A`.
+///
+/// This is the typed counterpart of [`AlignedBytes`](`crate::AlignedBytes`). It owns the elements.
+/// They are allocated when the struct is created and deallocated on drop.
+///
+/// # Guarantees
+///
+/// It is guaranteed that the elements allocated in this structure are aligned
+/// to the larger of [`A::size()`](`Alignment::size`) and the alignment of `T`.
+///
+/// # Examples
+/// ```rust
+/// # use aligners::{AlignedArray, alignment::{self, Alignment}};
+/// let array: AlignedArray<f32, alignment::TwoTo<5>> = AlignedArray::from(&[1.0, 2.0, 3.0][..]);
+///
+/// assert_eq!(array.as_ptr() as usize % 32, 0);
+/// assert_eq!(**array, [1.0, 2.0, 3.0]);
+/// ```
+pub struct AlignedArray<T: Copy, A: Alignment> {
+    elems_ptr: NonNull<T>,
+    len: usize,
+    phantom: PhantomData<(Box<[T]>, A)>,
+}
+
+/// Slice of elements of type `T` aligned to a boundary represented by `A`.
+///
+/// This is the typed counterpart of [`AlignedSlice`](`crate::AlignedSlice`).
+///
+/// # Guarantees
+///
+/// It is guaranteed that the elements are aligned to the larger of
+/// [`A::size()`](`Alignment::size`) and the alignment of `T`.
+///
+/// # Safety
+///
+/// Because the used `repr` is [`transparent`](https://doc.rust-lang.org/reference/type-layout.html#the-transparent-representation),
+/// it is possible to directly [`std::mem::transmute`] a `[T]` into an [`AlignedTypedSlice<T, A>`] (and vice-versa).
+/// This is only safe if the original slice is already aligned to [`A::size()`](`Alignment::size`).
+#[repr(transparent)]
+pub struct AlignedTypedSlice<T, A: Alignment> {
+    phantom: PhantomData<A>,
+    elems: [T],
+}
+
+// SAFETY:
+// The elements are exclusively owned, exactly like in a `Box<[T]>`, and `A` is only a marker.
+unsafe impl<T: Copy + Send, A: Alignment> Send for AlignedArray<T, A> {}
+
+// SAFETY:
+// Shared access only gives out shared references to the elements, exactly like a `Box<[T]>`.
+unsafe impl<T: Copy + Sync, A: Alignment> Sync for AlignedArray<T, A> {}
+
+impl<T: Copy, A: Alignment> AlignedArray<T, A> {
+    // Alignment of the allocation, the larger of the alignment of `A` and `T`.
+    fn alignment() -> usize {
+        std::cmp::max(A::size(), mem::align_of::<T>())
+    }
+
+    fn get_layout(len: usize) -> Layout {
+        let size = len
+            .checked_mul(mem::size_of::<T>())
+            .filter(|&size| size <= isize::MAX as usize)
+            .unwrap_or_else(|| {
+                panic!("cannot allocate more than `isize::MAX` bytes, attempted to allocate {len} elements")
+            });
+
+        Layout::from_size_align(size, Self::alignment()).unwrap()
+    }
+
+    // Pointer representing a zero-sized allocation aligned to `Self::alignment()`.
+    fn dangling() -> NonNull<T> {
+        // SAFETY:
+        // Same as `AlignedBytes::dangling`, the alignment is non-zero, so the pointer is non-null.
+        unsafe {
+            #[cfg(miri)]
+            let raw_ptr = std::ptr::without_provenance_mut(Self::alignment());
+            #[cfg(not(miri))]
+            let raw_ptr = Self::alignment() as *mut T;
+
+            NonNull::new_unchecked(raw_ptr)
+        }
+    }
+
+    // Allocates space for `len` elements, zeroing it if `zeroed` is set.
+    fn allocate(len: usize, zeroed: bool) -> Self {
+        let layout = Self::get_layout(len);
+
+        if layout.size() == 0 {
+            return Self {
+                elems_ptr: Self::dangling(),
+                len,
+                phantom: PhantomData,
+            };
+        }
+
+        // SAFETY:
+        // Layout is guaranteed to be of non-zero size at this point.
+        let raw_ptr = unsafe {
+            if zeroed {
+                std::alloc::alloc_zeroed(layout)
+            } else {
+                std::alloc::alloc(layout)
+            }
+        };
+        let ptr = NonNull::new(raw_ptr.cast::<T>())
+            .unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+
+        Self {
+            elems_ptr: ptr,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Create new array of `len` elements with all bytes set to zero.
+    ///
+    /// # Safety
+    /// The all-zero bit pattern must be a valid value of `T`. This is the case for
+    /// all integer and floating point types.
+    ///
+    /// # Panics
+    /// If the size of the array in bytes exceeds `isize::MAX` or allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedArray, alignment};
+    /// // SAFETY: zero is a valid `f32`.
+    /// let array: AlignedArray<f32, alignment::TwoTo<5>> = unsafe { AlignedArray::new_zeroed(8) };
+    ///
+    /// assert_eq!(**array, [0.0; 8]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn new_zeroed(len: usize) -> Self {
+        Self::allocate(len, true)
+    }
+
+    /// Return the size of the alignment in bytes.
+    ///
+    /// This is the larger of [`A::size()`](`Alignment::size`) and the alignment of `T`.
+    #[must_use]
+    #[inline(always)]
+    pub fn alignment_size(&self) -> usize {
+        Self::alignment()
+    }
+}
+
+impl<T: Copy, A: Alignment> AlignedTypedSlice<T, A> {
+    /// Return the size of the alignment in bytes.
+    ///
+    /// This is the larger of [`A::size()`](`Alignment::size`) and the alignment of `T`.
+    #[must_use]
+    #[inline(always)]
+    pub fn alignment_size(&self) -> usize {
+        AlignedArray::<T, A>::alignment()
+    }
+}
+
+impl<T: Copy, A: Alignment> Drop for AlignedArray<T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        let layout = Self::get_layout(self.len);
+
+        if layout.size() == 0 {
+            return;
+        }
+
+        // SAFETY:
+        // `ptr` is allocated in `allocate` with a layout constructed using the same function
+        // from the same length. `T: Copy`, so the elements need not be dropped.
+        unsafe { std::alloc::dealloc(self.elems_ptr.as_ptr().cast::<u8>(), layout) }
+    }
+}
+
+impl<T: Copy, A: Alignment> From<&[T]> for AlignedArray<T, A> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        let array = Self::allocate(slice.len(), false);
+
+        // SAFETY:
+        // - src is valid for reading `slice.len()` elements.
+        // - dst is valid for writing `slice.len()` elements, since that many were allocated.
+        //   For a zero-sized allocation, the dangling pointer is valid for a zero-sized copy.
+        // - The regions do not overlap, since dst is a fresh allocation.
+        // - Both pointers are properly aligned for `T`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), array.elems_ptr.as_ptr(), slice.len())
+        };
+
+        array
+    }
+}
+
+impl<T: Copy, A: Alignment> Clone for AlignedArray<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let slice: &[T] = self;
+        slice.into()
+    }
+}
+
+impl<T: Copy, A: Alignment> Default for AlignedArray<T, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::allocate(0, false)
+    }
+}
+
+impl<T: Copy, A: Alignment> Deref for AlignedArray<T, A> {
+    type Target = AlignedTypedSlice<T, A>;
+
+    #[inline]
+    fn deref(&self) -> &AlignedTypedSlice<T, A> {
+        // SAFETY:
+        // - `elems_ptr` points to `self.len` initialized elements, or is dangling and aligned
+        //   if the allocation is zero-sized;
+        // - transmute is safe because of AlignedTypedSlice's repr(transparent).
+        unsafe {
+            let slice = std::slice::from_raw_parts(self.elems_ptr.as_ptr(), self.len);
+            mem::transmute::<&[T], &AlignedTypedSlice<T, A>>(slice)
+        }
+    }
+}
+
+impl<T: Copy, A: Alignment> DerefMut for AlignedArray<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut AlignedTypedSlice<T, A> {
+        // SAFETY:
+        // Same as for `deref`, and the slice is borrowed mutably through `self`.
+        unsafe {
+            let slice = std::slice::from_raw_parts_mut(self.elems_ptr.as_ptr(), self.len);
+            mem::transmute::<&mut [T], &mut AlignedTypedSlice<T, A>>(slice)
+        }
+    }
+}
+
+impl<T, A: Alignment> Deref for AlignedTypedSlice<T, A> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.elems
+    }
+}
+
+impl<T, A: Alignment> DerefMut for AlignedTypedSlice<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.elems
+    }
+}
+
+impl<T: Copy + PartialEq, A: Alignment> PartialEq for AlignedArray<T, A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let slice: &[T] = self;
+        let other_slice: &[T] = other;
+
+        slice.eq(other_slice)
+    }
+}
+
+impl<T: std::fmt::Debug, A: Alignment> std::fmt::Debug for AlignedTypedSlice<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.elems, f)
+    }
+}
+
+impl<T: Copy + std::fmt::Debug, A: Alignment> std::fmt::Debug for AlignedArray<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deref: &AlignedTypedSlice<T, A> = self;
+        std::fmt::Debug::fmt(deref, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedArray};
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn f32_array_is_simd_aligned() {
+        use crate::alignment::{Alignment, SimdBlock};
+
+        let source = (0..100).map(|x| x as f32).collect::<Vec<_>>();
+        let array: AlignedArray<f32, SimdBlock> = AlignedArray::from(source.as_slice());
+
+        assert_aligned(array.as_ptr(), SimdBlock::size());
+        assert_eq!(**array, *source);
+    }
+
+    #[test]
+    fn new_zeroed_i32_array() {
+        // SAFETY: zero is a valid i32.
+        let array: AlignedArray<i32, alignment::TwoTo<6>> = unsafe { AlignedArray::new_zeroed(33) };
+
+        assert_eq!(33, array.len());
+        assert!(array.iter().all(|&x| x == 0));
+        assert_aligned(array.as_ptr(), 64);
+    }
+
+    #[test]
+    fn alignment_is_at_least_alignment_of_element_type() {
+        let array: AlignedArray<u64, alignment::One> = AlignedArray::from(&[1, 2, 3][..]);
+
+        assert_eq!(std::mem::align_of::<u64>(), array.alignment_size());
+        assert_aligned(array.as_ptr(), std::mem::align_of::<u64>());
+    }
+
+    #[test]
+    fn empty_array_is_aligned() {
+        let array: AlignedArray<f64, alignment::TwoTo<6>> = AlignedArray::default();
+
+        assert!(array.is_empty());
+        assert_aligned(array.as_ptr(), 64);
+    }
+
+    #[test]
+    fn clone_and_mutate_array() {
+        let array: AlignedArray<f32, alignment::TwoTo<5>> =
+            AlignedArray::from(&[1.0, 2.0, 3.0][..]);
+        let mut clone = array.clone();
+
+        clone[1] = 42.0;
+
+        assert_eq!(**array, [1.0, 2.0, 3.0]);
+        assert_eq!(**clone, [1.0, 42.0, 3.0]);
+        assert_aligned(clone.as_ptr(), 32);
+        assert_ne!(array, clone);
+    }
+
+    #[test]
+    fn array_of_zero_sized_elements() {
+        let array: AlignedArray<(), alignment::TwoTo<5>> = AlignedArray::from(&[(), (), ()][..]);
+
+        assert_eq!(3, array.len());
+        assert_aligned(array.as_ptr(), 32);
+    }
+}
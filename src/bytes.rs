@@ -1,14 +1,62 @@
 use crate::alignment::Alignment;
+use crate::slice::AlignedSlice;
 use cfg_if::cfg_if;
 use std::ptr::NonNull;
 
+#[cfg(feature = "bytes")]
+mod buf;
 mod cmp;
+mod dynamic;
+mod hex;
+mod into_iter;
+#[cfg(feature = "memmap2")]
+mod mmap;
 mod multiple;
+#[cfg(all(feature = "libc", unix))]
+mod os;
+#[cfg(feature = "rand")]
+mod random;
+mod secure;
+#[cfg(feature = "serde")]
+mod serialization;
+mod vec;
+#[cfg(all(feature = "windows", windows))]
+mod windows;
 
 #[doc(inline)]
 #[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
 pub use cmp::*;
 
+#[cfg(all(feature = "libc", unix))]
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use os::*;
+
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use dynamic::*;
+
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use hex::*;
+
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use into_iter::*;
+
+#[cfg(feature = "memmap2")]
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use mmap::*;
+
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use secure::*;
+
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use vec::*;
+
 cfg_if! {
     if #[cfg(feature = "simd")] {
         mod simd;
@@ -34,14 +82,73 @@ cfg_if! {
 pub struct AlignedBytes<A: Alignment> {
     bytes_ptr: std::ptr::NonNull<u8>,
     size: usize,
+    source: AllocationSource,
     phantom: std::marker::PhantomData<A>,
 }
 
+// SAFETY:
+// The bytes are exclusively owned, exactly like in a `Vec<u8>`, and freeing them is not tied
+// to the thread that allocated them. `A` is only a marker and no value of it is ever stored.
+unsafe impl<A: Alignment> Send for AlignedBytes<A> {}
+
+// SAFETY:
+// Shared access only gives out `&AlignedSlice<A>`, which is `Sync` as `[u8]` is,
+// and there is no interior mutability.
+unsafe impl<A: Alignment> Sync for AlignedBytes<A> {}
+
+// Origin of the allocation owned by an `AlignedBytes`, which determines how it must be freed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AllocationSource {
+    // Allocated by this crate with the layout returned by `get_layout(size)`.
+    Aligned,
+    // Taken over from a `Vec<u8>` of the given capacity, so it must be freed as that `Vec`.
+    Vec {
+        capacity: usize,
+    },
+    // Large pages allocated with `VirtualAlloc`, so they must be released with `VirtualFree`.
+    #[cfg(all(feature = "windows", windows))]
+    VirtualAlloc,
+}
+
 impl<A: Alignment> AlignedBytes<A> {
+    // Wrap an allocation of `size` bytes at `bytes_ptr` that must be freed according to `source`.
+    fn from_allocation(bytes_ptr: NonNull<u8>, size: usize, source: AllocationSource) -> Self {
+        Self {
+            bytes_ptr,
+            size,
+            source,
+            phantom: std::marker::PhantomData {},
+        }
+    }
+
     fn get_layout(size: usize) -> std::alloc::Layout {
         std::alloc::Layout::from_size_align(size, A::size()).unwrap()
     }
 
+    // Pointer representing a zero-sized allocation aligned to `A`.
+    fn dangling() -> NonNull<u8> {
+        // SAFETY:
+        // A zero-sized allocation can be represented by any pointer that is
+        // 1. non-null
+        // 2. properly aligned
+        // The simplest value that satisfies this is just the alignment value reinterpreted as a pointer.
+        // This is the strategy used by the standard library for zero-sized allocations
+        // (like a Box::new(()), or of any other ZST), usually employed by calling NonNull::dangling().
+        // This is the same implementation (https://doc.rust-lang.org/src/core/ptr/non_null.rs.html#88),
+        // but for `A::size()` alignment.
+        // The only requirement of new_unchecked is the pointer being not-null, and A::size() must be > 0.
+        unsafe {
+            // Use strict pointer functions if enabled.
+            // See https://github.com/V0ldek/aligners/issues/34
+            #[cfg(miri)]
+            let raw_ptr = std::ptr::without_provenance_mut(A::size());
+            #[cfg(not(miri))]
+            let raw_ptr = A::size() as *mut u8;
+
+            NonNull::new_unchecked(raw_ptr)
+        }
+    }
+
     /// Create new, possibly uninitialized, block of bytes of given length.
     ///
     /// # Safety
@@ -60,6 +167,10 @@ impl<A: Alignment> AlignedBytes<A> {
 
     // Extracted so that this fn isn't all in an `unsafe` context by default.
     fn new_impl(size: usize) -> Self {
+        if size == 0 {
+            return Self::default();
+        }
+
         if size > (isize::MAX as usize) {
             panic!("cannot allocate more than `isize::MAX` bytes, attempted to allocate {size}");
         }
@@ -71,11 +182,7 @@ impl<A: Alignment> AlignedBytes<A> {
         let raw_ptr = unsafe { std::alloc::alloc(layout) };
         let ptr = std::ptr::NonNull::new(raw_ptr).unwrap();
 
-        Self {
-            bytes_ptr: ptr,
-            size,
-            phantom: std::marker::PhantomData {},
-        }
+        Self::from_allocation(ptr, size, AllocationSource::Aligned)
     }
 
     /// Create new block of bytes of given length and initialize each byte to a function
@@ -124,11 +231,7 @@ impl<A: Alignment> AlignedBytes<A> {
         let raw_ptr = unsafe { std::alloc::alloc_zeroed(layout) };
         let ptr = std::ptr::NonNull::new(raw_ptr).unwrap();
 
-        Self {
-            bytes_ptr: ptr,
-            size,
-            phantom: std::marker::PhantomData {},
-        }
+        Self::from_allocation(ptr, size, AllocationSource::Aligned)
     }
 
     /// Create a new block of bytes by copying the given bytes
@@ -138,6 +241,9 @@ impl<A: Alignment> AlignedBytes<A> {
     /// This is primarily useful to guarantee that [`AlignedBlockIterator`](crate::iterators::AlignedBlockIterator)
     /// returns full blocks of size exactly equal to the alignment,
     /// as otherwise the final block can be potentially smaller.
+    ///
+    /// # Panics
+    /// If the padded length overflows `usize` or allocating memory fails.
     #[must_use]
     #[inline]
     pub fn new_padded(bytes: &[u8]) -> Self {
@@ -146,12 +252,7 @@ impl<A: Alignment> AlignedBytes<A> {
         }
 
         let size = bytes.len();
-        let padding = if size % A::size() == 0 {
-            0
-        } else {
-            A::size() - size % A::size()
-        };
-        let padded_size = size + padding;
+        let padded_size = Self::padded_len(size);
 
         let mut aligned = Self::new_zeroed(padded_size);
         aligned[..size].copy_from_slice(bytes);
@@ -159,6 +260,252 @@ impl<A: Alignment> AlignedBytes<A> {
         aligned
     }
 
+    /// Create a new block of bytes by copying the given bytes
+    /// and padding them with `pad`, so that the total size is
+    /// divisible by the alignment size.
+    ///
+    /// This is the same as [`new_padded`](`AlignedBytes::new_padded`), only the padding is filled
+    /// with a custom byte instead of zeroes.
+    ///
+    /// # Panics
+    /// If the padded length overflows `usize` or allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let aligned = AlignedBytes::<alignment::Eight>::new_padded_with(&[1, 2, 3], 0xFF);
+    ///
+    /// assert_eq!(aligned, [1, 2, 3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new_padded_with(bytes: &[u8], pad: u8) -> Self {
+        let size = bytes.len();
+        let padded_size = Self::padded_len(size);
+
+        // SAFETY:
+        // All bytes are initialized right after.
+        let mut aligned = unsafe { Self::new(padded_size) };
+        let slice: &mut [u8] = &mut aligned;
+        let (data, padding) = slice.split_at_mut(size);
+        data.copy_from_slice(bytes);
+        padding.fill(pad);
+
+        aligned
+    }
+
+    /// Create a new block of bytes by concatenating the given slices.
+    ///
+    /// This is the aligned analogue of [`concat`](`slice::concat`) on a slice of byte slices.
+    /// The bytes are copied directly into a single allocation of the total length.
+    ///
+    /// # Panics
+    /// If the total length exceeds `isize::MAX` or allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let aligned = AlignedBytes::<alignment::Eight>::concat(&[&[1, 2], &[], &[3, 4, 5]]);
+    ///
+    /// assert_eq!(aligned, [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn concat(slices: &[&[u8]]) -> Self {
+        let size = slices
+            .iter()
+            .try_fold(0_usize, |acc, slice| acc.checked_add(slice.len()))
+            .expect("cannot allocate more than `isize::MAX` bytes, total length overflows `usize`");
+
+        // SAFETY:
+        // All bytes are initialized right after, since the slices sum up to `size`.
+        let mut aligned = unsafe { Self::new(size) };
+        let mut rest: &mut [u8] = &mut aligned;
+
+        for slice in slices {
+            let (target, tail) = rest.split_at_mut(slice.len());
+            target.copy_from_slice(slice);
+            rest = tail;
+        }
+
+        aligned
+    }
+
+    /// Return the length of the bytes that [`new_padded`](`AlignedBytes::new_padded`) would produce
+    /// for an input of `len` bytes, without allocating.
+    ///
+    /// This is `len` rounded up to the next multiple of [`A::size()`](`Alignment::size`).
+    ///
+    /// # Panics
+    /// If the padded length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// assert_eq!(0, AlignedBytes::<alignment::Eight>::padded_len(0));
+    /// assert_eq!(8, AlignedBytes::<alignment::Eight>::padded_len(3));
+    /// assert_eq!(16, AlignedBytes::<alignment::Eight>::padded_len(16));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn padded_len(len: usize) -> usize {
+        len.checked_next_multiple_of(A::size())
+            .unwrap_or_else(|| panic!("padded length of {len} bytes overflows `usize`"))
+    }
+
+    /// Take over the allocation of `vec` without copying, if it is already aligned to
+    /// [`A::size()`](`Alignment::size`).
+    ///
+    /// If the bytes of `vec` are not aligned, it is returned back unchanged in the [`Err`] variant,
+    /// so that it can be copied with the [`From`] implementation instead.
+    ///
+    /// ## Note
+    /// This cannot be a [`TryFrom`] implementation, since the blanket [`From`] implementation
+    /// for all `T: AsRef<[u8]>` already provides an infallible, copying conversion from [`Vec<u8>`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let vec = vec![1, 2, 3];
+    /// let aligned = AlignedBytes::<alignment::Four>::try_from_vec(vec)
+    ///     .unwrap_or_else(|vec| AlignedBytes::from(vec));
+    ///
+    /// assert_eq!(aligned, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_from_vec(vec: Vec<u8>) -> Result<Self, Vec<u8>> {
+        if vec.capacity() == 0 {
+            return Ok(Self::default());
+        }
+
+        if !(vec.as_ptr() as usize).is_multiple_of(A::size()) {
+            return Err(vec);
+        }
+
+        let mut vec = std::mem::ManuallyDrop::new(vec);
+        let size = vec.len();
+        let capacity = vec.capacity();
+        // SAFETY:
+        // `Vec` with non-zero capacity always holds a non-null pointer.
+        let bytes_ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+
+        Ok(Self::from_allocation(
+            bytes_ptr,
+            size,
+            AllocationSource::Vec { capacity },
+        ))
+    }
+
+    /// Decompose into the raw pointer to the bytes and their length.
+    ///
+    /// The ownership of the allocation is transferred to the caller, who is responsible
+    /// for freeing it, which can only be done by reconstructing the [`AlignedBytes`]
+    /// with [`from_raw_parts`](`AlignedBytes::from_raw_parts`).
+    ///
+    /// If the bytes were taken over from a [`Vec<u8>`] via [`try_from_vec`](`AlignedBytes::try_from_vec`),
+    /// or allocated in large pages on Windows, they are first copied into a new allocation,
+    /// since the raw parts cannot describe the layout of the original one.
+    #[must_use = "losing the pointer will leak memory"]
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut u8, usize) {
+        let bytes = match self.source {
+            AllocationSource::Aligned => self,
+            AllocationSource::Vec { .. } => {
+                let slice: &[u8] = &self;
+                slice.into()
+            }
+            #[cfg(all(feature = "windows", windows))]
+            AllocationSource::VirtualAlloc => {
+                let slice: &[u8] = &self;
+                slice.into()
+            }
+        };
+        let mut bytes = std::mem::ManuallyDrop::new(bytes);
+
+        (bytes.as_mut_ptr(), bytes.len())
+    }
+
+    /// Reconstruct the bytes from the raw parts returned by [`into_raw_parts`](`AlignedBytes::into_raw_parts`).
+    ///
+    /// # Safety
+    /// - `ptr` and `len` must be exactly the parts returned by a call to
+    ///   [`into_raw_parts`](`AlignedBytes::into_raw_parts`) on an [`AlignedBytes`] of the same alignment `A`.
+    ///   The allocation is freed using a layout computed from `len` and [`A::size()`](`Alignment::size`),
+    ///   so any other pointer, length, or alignment will cause undefined behaviour.
+    /// - The parts must be used to reconstruct an [`AlignedBytes`] at most once,
+    ///   as otherwise the allocation would be freed multiple times.
+    #[must_use]
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: *mut u8, len: usize) -> Self {
+        Self::from_allocation(NonNull::new_unchecked(ptr), len, AllocationSource::Aligned)
+    }
+
+    /// Consume the bytes and leak the allocation, returning a mutable reference to the aligned slice.
+    ///
+    /// The memory is never freed. This is useful for buffers that live for the rest of the program,
+    /// since the lifetime `'a` can be chosen to be `'static`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, AlignedSlice, alignment};
+    /// let scratch: &'static mut AlignedSlice<alignment::Page> = AlignedBytes::new_zeroed(64).leak();
+    /// scratch[0] = 42;
+    ///
+    /// assert_eq!(42, scratch[0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut AlignedSlice<A>
+    where
+        A: 'a,
+    {
+        let mut bytes = std::mem::ManuallyDrop::new(self);
+
+        // SAFETY:
+        // The bytes are never deallocated, since we never drop them, so the slice is valid
+        // for any lifetime. We consumed the only owner, so the reference is exclusive.
+        // transmute is safe because of AlignedSlice's repr(transparent).
+        unsafe {
+            let slice = std::slice::from_raw_parts_mut(bytes.as_mut_ptr(), bytes.len());
+            std::mem::transmute::<&'a mut [u8], &'a mut AlignedSlice<A>>(slice)
+        }
+    }
+
+    /// Convert into a [`Box<[u8]>`](`Box`) with the same contents.
+    ///
+    /// The bytes are copied into a new allocation made by the global allocator
+    /// with the default alignment for `u8`, so the alignment guarantee is lost.
+    /// The original aligned allocation is freed.
+    #[must_use]
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[u8]> {
+        let slice: &[u8] = &self;
+        slice.into()
+    }
+
+    /// Convert into a [`Vec<u8>`] with the same contents.
+    ///
+    /// The bytes are copied into a new allocation made by the global allocator
+    /// with the default alignment for `u8`, so the alignment guarantee is lost.
+    /// The original aligned allocation is freed.
+    #[must_use]
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        let slice: &[u8] = &self;
+        slice.to_vec()
+    }
+
+    /// Check whether the bytes are actually aligned to [`A::size()`](`Alignment::size`).
+    ///
+    /// This is always `true` for correctly constructed values, and is meant for debug assertions
+    /// catching misuse of `unsafe` constructors. The check uses the integer value of the pointer,
+    /// since `pointer::align_offset` is allowed to give false negatives.
+    #[must_use]
+    #[inline]
+    pub fn verify_alignment(&self) -> bool {
+        crate::is_aligned_to(self.as_ptr(), A::size())
+    }
+
     /// Return the size of the alignment in bytes.
     ///
     /// ## Note
@@ -205,17 +552,36 @@ impl<A: Alignment> Drop for AlignedBytes<A> {
     fn drop(&mut self) {
         use std::alloc::dealloc;
 
-        if self.size == 0 {
-            return;
+        match self.source {
+            AllocationSource::Aligned => {
+                if self.size == 0 {
+                    return;
+                }
+
+                let layout = Self::get_layout(self.size);
+
+                // SAFETY:
+                // `ptr` is allocated in `new_internal` and
+                // layout is constructed using the same function and will be the same.
+                // This relies on `A::size()` being constant and self.size not being mutated ever.
+                unsafe { dealloc(self.bytes_ptr.as_ptr(), layout) }
+            }
+            AllocationSource::Vec { capacity } => {
+                // SAFETY:
+                // The pointer, length and capacity are exactly those of the `Vec` that was taken over
+                // in `try_from_vec`, so reconstructing it is sound and dropping it frees the allocation
+                // with the layout it was allocated with.
+                unsafe {
+                    drop(Vec::from_raw_parts(
+                        self.bytes_ptr.as_ptr(),
+                        self.size,
+                        capacity,
+                    ))
+                }
+            }
+            #[cfg(all(feature = "windows", windows))]
+            AllocationSource::VirtualAlloc => self.virtual_free(),
         }
-
-        let layout = Self::get_layout(self.size);
-
-        // SAFETY:
-        // `ptr` is allocated in `new_internal` and
-        // layout is constructed using the same function and will be the same.
-        // This relies on `A::size()` being constant and self.size not being mutated ever.
-        unsafe { dealloc(self.bytes_ptr.as_ptr(), layout) }
     }
 }
 
@@ -226,20 +592,31 @@ impl<T: AsRef<[u8]>, A: Alignment> From<T> for AlignedBytes<A> {
         let bytes;
 
         // SAFETY:
-        // Uninitialized `new` is safe since we immediately initialize the bytes with `s`, and `copy` is safe because:
+        // Uninitialized `new` is safe since we immediately initialize the bytes with `s`, and `copy_nonoverlapping` is safe because:
         // - src is valid for reading `slice.len()` bytes.
         // - dst is valid for writing `slice.len()` bytes, since `Self::new` allocates that much
         //   bytes, but aligned.
+        // - The regions do not overlap, since dst is a fresh allocation that `s` cannot point into.
+        //   If both are empty, a zero-length copy is valid for any pair of non-null aligned pointers.
         // - Both pointers are properly aligned, since proper alignment for `u8` is 1.
         unsafe {
             bytes = Self::new(slice.len());
-            std::ptr::copy(slice.as_ptr(), bytes.bytes_ptr.as_ptr(), slice.len())
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), bytes.bytes_ptr.as_ptr(), slice.len())
         };
 
         bytes
     }
 }
 
+impl<A: Alignment> FromIterator<u8> for AlignedBytes<A> {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        iter.into_iter()
+            .collect::<AlignedVec<A>>()
+            .into_aligned_bytes()
+    }
+}
+
 impl<A: Alignment> std::fmt::Debug for AlignedBytes<A> {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -251,38 +628,14 @@ impl<A: Alignment> std::fmt::Debug for AlignedBytes<A> {
 impl<A: Alignment> Default for AlignedBytes<A> {
     #[inline]
     fn default() -> Self {
-        // SAFETY:
-        // A zero-sized allocation can be represented by any pointer that is
-        // 1. non-null
-        // 2. properly aligned
-        // The simplest value that satisfies this is just the alignment value reinterpreted as a pointer.
-        // This is the strategy used by the standard library for zero-sized allocations
-        // (like a Box::new(()), or of any other ZST), usually employed by calling NonNull::dangling().
-        // This is the same implementation (https://doc.rust-lang.org/src/core/ptr/non_null.rs.html#88),
-        // but for `A::size()` alignment.
-        // The only requirement of new_unchecked is the pointer being not-null, and A::size() must be > 0.
-        let bytes_ptr = unsafe {
-            // Use strict pointer functions if enabled.
-            // See https://github.com/V0ldek/aligners/issues/34
-            #[cfg(miri)]
-            let raw_ptr = std::ptr::without_provenance_mut(A::size());
-            #[cfg(not(miri))]
-            let raw_ptr = A::size() as *mut u8;
-
-            NonNull::new_unchecked(raw_ptr)
-        };
-        Self {
-            bytes_ptr,
-            size: 0,
-            phantom: Default::default(),
-        }
+        Self::from_allocation(Self::dangling(), 0, AllocationSource::Aligned)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test::assert_aligned;
-    use crate::{alignment, AlignedBytes};
+    use crate::{alignment, AlignedBytes, AlignedSlice};
 
     #[test]
     fn empty_bytes_are_aligned() {
@@ -295,10 +648,263 @@ mod tests {
         assert_aligned(bytes.as_ptr(), 2usize.pow(15));
     }
 
+    #[test]
+    fn new_padded_with_fills_padding_with_custom_byte() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> =
+            AlignedBytes::new_padded_with(&[1; 21], 0xFF);
+
+        assert_eq!(32, bytes.len());
+        assert!(bytes[..21].iter().all(|&x| x == 1));
+        assert!(bytes[21..].iter().all(|&x| x == 0xFF));
+        assert_aligned(bytes.as_ptr(), 16);
+    }
+
+    #[test]
+    fn new_padded_with_does_not_pad_block_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<3>> =
+            AlignedBytes::new_padded_with(&[1; 16], 0xFF);
+
+        assert_eq!(bytes, [1; 16]);
+    }
+
+    #[test]
+    fn new_padded_with_empty_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<3>> = AlignedBytes::new_padded_with(&[], 0xFF);
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn padded_len_of_exact_multiple_is_unchanged() {
+        assert_eq!(64, AlignedBytes::<alignment::TwoTo<5>>::padded_len(64));
+        assert_eq!(32, AlignedBytes::<alignment::TwoTo<5>>::padded_len(32));
+    }
+
+    #[test]
+    fn padded_len_of_non_multiple_is_rounded_up() {
+        assert_eq!(32, AlignedBytes::<alignment::TwoTo<5>>::padded_len(1));
+        assert_eq!(64, AlignedBytes::<alignment::TwoTo<5>>::padded_len(33));
+        assert_eq!(64, AlignedBytes::<alignment::TwoTo<5>>::padded_len(63));
+    }
+
+    #[test]
+    fn padded_len_of_zero_is_zero() {
+        assert_eq!(0, AlignedBytes::<alignment::TwoTo<5>>::padded_len(0));
+    }
+
+    #[test]
+    fn padded_len_of_largest_block_multiple_does_not_overflow() {
+        let len = usize::MAX - 31;
+
+        assert_eq!(len, AlignedBytes::<alignment::TwoTo<5>>::padded_len(len));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows `usize`")]
+    fn padded_len_of_usize_max_panics() {
+        let _ = AlignedBytes::<alignment::TwoTo<5>>::padded_len(usize::MAX);
+    }
+
+    #[test]
+    fn padded_len_matches_new_padded() {
+        for len in 0..100 {
+            let bytes = AlignedBytes::<alignment::TwoTo<4>>::new_padded(&vec![1; len]);
+
+            assert_eq!(
+                bytes.len(),
+                AlignedBytes::<alignment::TwoTo<4>>::padded_len(len)
+            );
+        }
+    }
+
+    #[test]
+    fn concat_three_slices() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::concat(&[&[1, 2, 3], &[4; 100], &[5, 6]]);
+
+        let slice: &[u8] = &bytes;
+
+        assert_eq!(105, slice.len());
+        assert_eq!(&slice[..3], [1, 2, 3]);
+        assert!(slice[3..103].iter().all(|&x| x == 4));
+        assert_eq!(&slice[103..], [5, 6]);
+        assert_aligned(bytes.as_ptr(), 64);
+    }
+
+    #[test]
+    fn concat_no_slices() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::concat(&[]);
+
+        assert!(bytes.is_empty());
+        assert_aligned(bytes.as_ptr(), 64);
+    }
+
+    #[test]
+    fn concat_empty_slices() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::concat(&[&[], &[]]);
+
+        assert!(bytes.is_empty());
+    }
+
     #[test]
     fn alignment_size_equal_to_alignment_type() {
         let bytes: AlignedBytes<alignment::TwoTo<7>> = AlignedBytes::new_zeroed(1024);
 
         assert_eq!(128, bytes.alignment_size());
     }
+
+    #[test]
+    fn from_large_unaligned_slice_copies_all_bytes() {
+        let source = (0..1024 * 1024 + 1)
+            .map(|i: usize| (i % 251) as u8)
+            .collect::<Vec<u8>>();
+        let unaligned = &source[1..];
+
+        let bytes: AlignedBytes<alignment::TwoTo<12>> = AlignedBytes::from(unaligned);
+
+        assert_eq!(bytes, *unaligned);
+        assert_aligned(bytes.as_ptr(), 4096);
+    }
+
+    #[test]
+    fn from_empty_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from(&[] as &[u8]);
+
+        assert!(bytes.is_empty());
+        assert_aligned(bytes.as_ptr(), 32);
+    }
+
+    #[test]
+    fn from_copy_of_other_aligned_bytes() {
+        // The source and the destination are distinct allocations, so this must not alias under Miri.
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..100).collect();
+        let copy: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from(&*bytes);
+
+        assert_eq!(bytes, copy);
+        assert_ne!(bytes.as_ptr(), copy.as_ptr());
+    }
+
+    #[test]
+    fn into_boxed_slice_round_trip() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3]);
+        let boxed = bytes.into_boxed_slice();
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from(&boxed);
+
+        assert_eq!(*boxed, [1, 2, 3]);
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn into_vec_round_trip() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3]);
+        let vec = bytes.into_vec();
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from(&vec);
+
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn into_vec_and_boxed_slice_of_empty_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = Default::default();
+        assert!(bytes.clone().into_vec().is_empty());
+        assert!(bytes.into_boxed_slice().is_empty());
+    }
+
+    #[test]
+    fn try_from_vec_reuses_aligned_allocation() {
+        let vec = vec![1, 2, 3];
+        let ptr = vec.as_ptr();
+        let bytes = AlignedBytes::<alignment::One>::try_from_vec(vec).unwrap();
+
+        assert_eq!(ptr, bytes.as_ptr());
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn try_from_vec_with_spare_capacity() {
+        let mut vec = Vec::with_capacity(100);
+        vec.extend_from_slice(&[1, 2, 3]);
+        let bytes = AlignedBytes::<alignment::One>::try_from_vec(vec).unwrap();
+
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn try_from_vec_checks_alignment() {
+        let vec = vec![1, 2, 3];
+        let ptr = vec.as_ptr();
+
+        match AlignedBytes::<alignment::TwoTo<12>>::try_from_vec(vec) {
+            Ok(bytes) => {
+                assert_eq!(ptr, bytes.as_ptr());
+                assert_aligned(bytes.as_ptr(), 4096);
+            }
+            Err(vec) => {
+                assert_eq!(ptr, vec.as_ptr());
+                assert_eq!(vec, [1, 2, 3]);
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_empty_vec() {
+        let bytes = AlignedBytes::<alignment::Eight>::try_from_vec(Vec::new()).unwrap();
+
+        assert!(bytes.is_empty());
+        assert_aligned(bytes.as_ptr(), 8);
+    }
+
+    #[test]
+    fn raw_parts_round_trip() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3]);
+        let (ptr, len) = bytes.into_raw_parts();
+
+        assert_aligned(ptr, 32);
+        assert_eq!(3, len);
+
+        // SAFETY:
+        // Parts were just obtained from `into_raw_parts` for the same alignment.
+        let bytes = unsafe { AlignedBytes::<alignment::TwoTo<5>>::from_raw_parts(ptr, len) };
+
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn raw_parts_round_trip_from_vec() {
+        let bytes = AlignedBytes::<alignment::One>::try_from_vec(vec![1, 2, 3]).unwrap();
+        let (ptr, len) = bytes.into_raw_parts();
+
+        // SAFETY:
+        // Parts were just obtained from `into_raw_parts` for the same alignment.
+        let bytes = unsafe { AlignedBytes::<alignment::One>::from_raw_parts(ptr, len) };
+
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn leak_returns_aligned_writable_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(4);
+        let leaked: &'static mut AlignedSlice<alignment::TwoTo<5>> = bytes.leak();
+        leaked[3] = 42;
+
+        assert_eq!(leaked, &[0, 0, 0, 42]);
+        assert_aligned(leaked.as_ptr(), 32);
+    }
+
+    #[test]
+    fn collect_from_iterator() {
+        let bytes: AlignedBytes<alignment::TwoTo<7>> = (0..=255).collect();
+
+        assert_eq!(bytes, (0..=255).collect::<Vec<u8>>());
+        assert_aligned(bytes.as_ptr(), 128);
+    }
+
+    #[test]
+    fn collect_from_empty_iterator() {
+        let bytes: AlignedBytes<alignment::Eight> = std::iter::empty().collect();
+
+        assert!(bytes.is_empty());
+        assert_aligned(bytes.as_ptr(), 8);
+    }
 }
@@ -0,0 +1,112 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedVec;
+use crate::slice::AlignedCursor;
+use ::bytes::buf::UninitSlice;
+use ::bytes::{Buf, BufMut};
+
+// Minimal number of bytes reserved when `chunk_mut` is called on a full vector.
+const MIN_CHUNK_RESERVE: usize = 64;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl<A: Alignment> Buf for AlignedCursor<'_, A> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.remaining_slice().len()
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.remaining_slice()
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "cannot advance past `remaining`: {} <= {}",
+            cnt,
+            self.remaining()
+        );
+
+        std::io::BufRead::consume(self, cnt);
+    }
+}
+
+/// The spare capacity exposed by [`chunk_mut`](`BufMut::chunk_mut`) is part of the aligned allocation,
+/// but it starts at an [`A::size()`](`Alignment::size`) boundary only if the length of the vector is a multiple of it.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+// SAFETY:
+// `chunk_mut` always returns the spare capacity of the allocation past the initialized bytes,
+// and `advance_mut` extends the length only within that capacity.
+unsafe impl<A: Alignment> BufMut for AlignedVec<A> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.len()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let spare = self.capacity() - self.len();
+        assert!(
+            cnt <= spare,
+            "cannot advance past the spare capacity: {} <= {}",
+            cnt,
+            spare
+        );
+
+        // SAFETY:
+        // The caller guarantees that the `cnt` bytes past the length have been initialized,
+        // and they are within capacity, as asserted above.
+        unsafe { self.set_len(self.len() + cnt) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.capacity() == self.len() {
+            self.reserve(MIN_CHUNK_RESERVE);
+        }
+
+        let spare = self.capacity() - self.len();
+
+        // SAFETY:
+        // The pointer is within the allocation (or one past the end if spare is zero),
+        // and the `spare` bytes after it are allocated, exclusively borrowed, and unused.
+        unsafe { UninitSlice::from_raw_parts_mut(self.as_mut_ptr().add(self.len()), spare) }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes, AlignedCursor, AlignedVec};
+    use ::bytes::{Buf, BufMut};
+
+    #[test]
+    fn put_slice_into_vec_preserves_alignment() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+
+        vec.put_slice(&[1, 2, 3]);
+        vec.put_u32(0x0405_0607);
+        vec.put_bytes(8, 100);
+
+        assert_aligned(vec.as_ptr(), 32);
+        assert_eq!(&vec[..7], [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(107, vec.len());
+        assert!(vec[7..].iter().all(|&x| x == 8));
+    }
+
+    #[test]
+    fn get_u32_from_cursor() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([0, 0, 0, 42, 1, 2]);
+        let mut cursor = AlignedCursor::new(&bytes);
+
+        assert_eq!(42, cursor.get_u32());
+        assert_eq!(2, cursor.remaining());
+        assert_eq!(cursor.chunk(), [1, 2]);
+    }
+}
@@ -62,6 +62,38 @@ impl<A: Alignment, const N: usize> PartialEq<[u8; N]> for AlignedBytes<A> {
         slice.eq(other)
     }
 }
+impl<A: Alignment> PartialEq<str> for AlignedBytes<A> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        let slice: &AlignedSlice<A> = self;
+
+        slice.eq(other)
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedBytes<A>> for str {
+    #[inline]
+    fn eq(&self, other: &AlignedBytes<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<A: Alignment> PartialEq<&str> for AlignedBytes<A> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        let slice: &AlignedSlice<A> = self;
+
+        slice.eq(other)
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedBytes<A>> for &str {
+    #[inline]
+    fn eq(&self, other: &AlignedBytes<A>) -> bool {
+        other.eq(self)
+    }
+}
+
 impl<A: Alignment> PartialOrd for AlignedBytes<A> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
@@ -92,6 +124,25 @@ mod test {
     use super::*;
     use crate::alignment::*;
 
+    #[test]
+    fn bytes_are_equal_to_str_with_same_bytes() {
+        let bytes: AlignedBytes<Eight> = AlignedBytes::from("abc");
+
+        assert_eq!(bytes, "abc");
+        assert_eq!("abc", bytes);
+        assert_eq!(bytes, *"abc");
+        assert_eq!(*"abc", bytes);
+    }
+
+    #[test]
+    fn bytes_are_not_equal_to_str_with_different_bytes() {
+        let bytes: AlignedBytes<Eight> = AlignedBytes::from("abc");
+
+        assert_ne!(bytes, "abd");
+        assert_ne!(bytes, "");
+        assert_ne!("abcd", bytes);
+    }
+
     #[test]
     fn hash_of_two_equal_structures_is_equal() {
         use std::{
@@ -0,0 +1,197 @@
+use crate::alignment::Alignment;
+use crate::slice::AlignedSlice;
+use std::alloc::{Layout, LayoutError};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// Bytes aligned to a boundary chosen at runtime.
+///
+/// This is the counterpart of [`AlignedBytes`](`super::AlignedBytes`) for when the alignment
+/// is not known at compile time, for example when it is read from configuration.
+/// The alignment is stored alongside the bytes instead of being carried by a type parameter.
+///
+/// # Guarantees
+///
+/// It is guaranteed that the bytes allocated in this structure are aligned to
+/// [`alignment()`](`DynAlignedBytes::alignment`) bytes. Since this is not reflected in the type,
+/// none of the APIs requiring an [`Alignment`] type can rely on it directly.
+/// Use [`as_aligned_slice`](`DynAlignedBytes::as_aligned_slice`) to get a statically aligned view.
+pub struct DynAlignedBytes {
+    bytes_ptr: NonNull<u8>,
+    size: usize,
+    alignment: usize,
+}
+
+// SAFETY:
+// Same as for `AlignedBytes`, the bytes are exclusively owned like in a `Vec<u8>`.
+unsafe impl Send for DynAlignedBytes {}
+
+// SAFETY:
+// Same as for `AlignedBytes`, there is no interior mutability.
+unsafe impl Sync for DynAlignedBytes {}
+
+impl DynAlignedBytes {
+    /// Create new block of bytes of given length, aligned to `alignment` bytes,
+    /// and initialize them to all-zeroes.
+    ///
+    /// # Errors
+    /// If `alignment` is not a power of two, or `size` rounded up to `alignment` exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::DynAlignedBytes;
+    /// let bytes = DynAlignedBytes::new_zeroed(1024, 128).unwrap();
+    ///
+    /// assert_eq!(128, bytes.alignment());
+    /// assert_eq!(bytes.as_ptr() as usize % 128, 0);
+    /// assert!(DynAlignedBytes::new_zeroed(1024, 48).is_err());
+    /// ```
+    #[inline]
+    pub fn new_zeroed(size: usize, alignment: usize) -> Result<Self, LayoutError> {
+        let layout = Layout::from_size_align(size, alignment)?;
+
+        if size == 0 {
+            return Ok(Self {
+                bytes_ptr: Self::dangling(alignment),
+                size,
+                alignment,
+            });
+        }
+
+        // SAFETY:
+        // Layout is guaranteed to be of non-zero size at this point.
+        let raw_ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+        let bytes_ptr =
+            NonNull::new(raw_ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+
+        Ok(Self {
+            bytes_ptr,
+            size,
+            alignment,
+        })
+    }
+
+    // Pointer representing a zero-sized allocation aligned to `alignment`.
+    fn dangling(alignment: usize) -> NonNull<u8> {
+        // SAFETY:
+        // Same as `AlignedBytes::dangling`, the alignment is a power of two, so non-zero.
+        unsafe {
+            #[cfg(miri)]
+            let raw_ptr = std::ptr::without_provenance_mut(alignment);
+            #[cfg(not(miri))]
+            let raw_ptr = alignment as *mut u8;
+
+            NonNull::new_unchecked(raw_ptr)
+        }
+    }
+
+    /// Return the size of the alignment in bytes.
+    #[must_use]
+    #[inline(always)]
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// View the bytes as an [`AlignedSlice<A>`] if `A` is not stricter than the runtime alignment.
+    ///
+    /// Returns [`None`] if [`A::size()`](`Alignment::size`) is greater than [`alignment()`](`DynAlignedBytes::alignment`).
+    #[must_use]
+    #[inline]
+    pub fn as_aligned_slice<A: Alignment>(&self) -> Option<&AlignedSlice<A>> {
+        if A::size() > self.alignment {
+            return None;
+        }
+
+        // SAFETY:
+        // Both alignments are powers of two, so the bytes aligned to `self.alignment`
+        // are also aligned to the smaller or equal `A::size()`.
+        // transmute is safe because of AlignedSlice's repr(transparent).
+        unsafe { Some(std::mem::transmute::<&[u8], &AlignedSlice<A>>(self)) }
+    }
+}
+
+impl Drop for DynAlignedBytes {
+    #[inline]
+    fn drop(&mut self) {
+        if self.size == 0 {
+            return;
+        }
+
+        // SAFETY:
+        // The layout was successfully constructed from the same values in `new_zeroed`.
+        let layout = unsafe { Layout::from_size_align_unchecked(self.size, self.alignment) };
+
+        // SAFETY:
+        // `ptr` is allocated in `new_zeroed` with the same layout.
+        unsafe { std::alloc::dealloc(self.bytes_ptr.as_ptr(), layout) }
+    }
+}
+
+impl Deref for DynAlignedBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        // SAFETY:
+        // The pointer is to an allocation of `self.size` initialized bytes,
+        // or a dangling non-null pointer if the size is zero.
+        unsafe { std::slice::from_raw_parts(self.bytes_ptr.as_ptr(), self.size) }
+    }
+}
+
+impl DerefMut for DynAlignedBytes {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY:
+        // Same as for `deref`, and the returned borrow is tied to the mutable borrow of `self`.
+        unsafe { std::slice::from_raw_parts_mut(self.bytes_ptr.as_ptr(), self.size) }
+    }
+}
+
+impl std::fmt::Debug for DynAlignedBytes {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deref: &[u8] = self;
+        std::fmt::Debug::fmt(deref, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynAlignedBytes;
+    use crate::alignment;
+    use crate::test::assert_aligned;
+
+    #[test]
+    fn runtime_alignment_is_respected() {
+        let mut bytes = DynAlignedBytes::new_zeroed(1000, 128).unwrap();
+        bytes[999] = 1;
+
+        assert_aligned(bytes.as_ptr(), 128);
+        assert_eq!(128, bytes.alignment());
+        assert_eq!(1000, bytes.len());
+        assert!(bytes[..999].iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn empty_bytes_are_aligned() {
+        let bytes = DynAlignedBytes::new_zeroed(0, 128).unwrap();
+
+        assert_aligned(bytes.as_ptr(), 128);
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn non_power_of_two_alignment_is_rejected() {
+        assert!(DynAlignedBytes::new_zeroed(1000, 48).is_err());
+        assert!(DynAlignedBytes::new_zeroed(1000, 0).is_err());
+    }
+
+    #[test]
+    fn as_aligned_slice_respects_runtime_alignment() {
+        let bytes = DynAlignedBytes::new_zeroed(16, 128).unwrap();
+
+        assert!(bytes.as_aligned_slice::<alignment::TwoTo<7>>().is_some());
+        assert!(bytes.as_aligned_slice::<alignment::TwoTo<8>>().is_none());
+    }
+}
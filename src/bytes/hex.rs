@@ -0,0 +1,155 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+use std::fmt;
+
+/// Error returned by [`AlignedBytes::from_hex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The input has an odd number of characters, so it does not describe whole bytes.
+    OddLength,
+    /// The input contains a character that is not a hex digit.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+        /// Byte index of the character in the input.
+        index: usize,
+    },
+}
+
+impl fmt::Display for FromHexError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => write!(f, "hex string has an odd length"),
+            Self::InvalidCharacter { character, index } => {
+                write!(
+                    f,
+                    "invalid hex character {:?} at index {}",
+                    character, index
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromHexError {}
+
+impl<A: Alignment> AlignedBytes<A> {
+    /// Parse a string of hex digits into new aligned bytes, two digits per byte.
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Errors
+    /// [`FromHexError::OddLength`] if the input length is odd, and [`FromHexError::InvalidCharacter`]
+    /// for the first character that is not a hex digit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes, FromHexError};
+    ///
+    /// let bytes = AlignedBytes::<alignment::Eight>::from_hex("deadBEEF").unwrap();
+    ///
+    /// assert_eq!(bytes, [0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(
+    ///     AlignedBytes::<alignment::Eight>::from_hex("abc").unwrap_err(),
+    ///     FromHexError::OddLength
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_hex(s: &str) -> Result<Self, FromHexError> {
+        let digits = s.as_bytes();
+
+        if !digits.len().is_multiple_of(2) {
+            return Err(FromHexError::OddLength);
+        }
+
+        let mut bytes = Self::new_zeroed(digits.len() / 2);
+
+        for (i, pair) in digits.chunks_exact(2).enumerate() {
+            let high = hex_digit(s, 2 * i, pair[0])?;
+            let low = hex_digit(s, 2 * i + 1, pair[1])?;
+            bytes[i] = (high << 4) | low;
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn hex_digit(s: &str, index: usize, digit: u8) -> Result<u8, FromHexError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(FromHexError::InvalidCharacter {
+            // Report the whole character, even if the byte is a part of a multi-byte one.
+            character: s[floor_char_boundary(s, index)..]
+                .chars()
+                .next()
+                .unwrap_or_default(),
+            index,
+        }),
+    }
+}
+
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromHexError;
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn from_valid_hex() {
+        let bytes = AlignedBytes::<alignment::TwoTo<5>>::from_hex("00ff7fAb").unwrap();
+
+        assert_aligned(bytes.as_ptr(), 32);
+        assert_eq!(bytes, [0x00, 0xFF, 0x7F, 0xAB]);
+    }
+
+    #[test]
+    fn from_empty_hex() {
+        let bytes = AlignedBytes::<alignment::TwoTo<5>>::from_hex("").unwrap();
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn from_odd_length_hex_is_an_error() {
+        let err = AlignedBytes::<alignment::TwoTo<5>>::from_hex("abc").unwrap_err();
+
+        assert_eq!(FromHexError::OddLength, err);
+    }
+
+    #[test]
+    fn from_hex_with_invalid_character_is_an_error() {
+        let err = AlignedBytes::<alignment::TwoTo<5>>::from_hex("abzd").unwrap_err();
+
+        assert_eq!(
+            FromHexError::InvalidCharacter {
+                character: 'z',
+                index: 2
+            },
+            err
+        );
+        assert_eq!("invalid hex character 'z' at index 2", err.to_string());
+    }
+
+    #[test]
+    fn from_hex_with_multibyte_character_reports_whole_character() {
+        let err = AlignedBytes::<alignment::TwoTo<5>>::from_hex("0ąb").unwrap_err();
+
+        assert_eq!(
+            FromHexError::InvalidCharacter {
+                character: 'ą',
+                index: 1
+            },
+            err
+        );
+    }
+}
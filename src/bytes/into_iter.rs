@@ -0,0 +1,114 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+use std::iter::FusedIterator;
+
+/// Owning iterator over the bytes of an [`AlignedBytes`].
+///
+/// Created by the [`IntoIterator`] implementation for [`AlignedBytes`].
+/// The underlying allocation is freed when the iterator is dropped.
+pub struct IntoAlignedBytesIter<A: Alignment> {
+    bytes: AlignedBytes<A>,
+    front: usize,
+    back: usize,
+}
+
+impl<A: Alignment> IntoIterator for AlignedBytes<A> {
+    type Item = u8;
+    type IntoIter = IntoAlignedBytesIter<A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let back = self.len();
+
+        // The iterator takes ownership of the bytes, and they get deallocated when it is dropped.
+        IntoAlignedBytesIter {
+            bytes: self,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<A: Alignment> Iterator for IntoAlignedBytesIter<A> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let byte = self.bytes[self.front];
+        self.front += 1;
+
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.back - self.front;
+        (size, Some(size))
+    }
+}
+
+impl<A: Alignment> DoubleEndedIterator for IntoAlignedBytesIter<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.bytes[self.back])
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for IntoAlignedBytesIter<A> {}
+
+impl<A: Alignment> FusedIterator for IntoAlignedBytesIter<A> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn into_iter_yields_all_bytes() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let collected = bytes.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(collected, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn into_iter_from_both_ends_meets_in_the_middle() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let mut iter = bytes.into_iter();
+
+        assert_eq!(Some(1), iter.next());
+        assert_eq!(Some(5), iter.next_back());
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(4), iter.next_back());
+        assert_eq!(Some(2), iter.next());
+        assert_eq!(Some(3), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn into_iter_dropped_partially_consumed() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(100);
+        let mut iter = bytes.into_iter();
+
+        assert_eq!(Some(0), iter.next());
+    }
+
+    #[test]
+    fn into_iter_over_empty_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = Default::default();
+        let mut iter = bytes.into_iter();
+
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::alignment::{self, Alignment};
+use crate::bytes::AlignedBytes;
+use crate::slice::AlignedSlice;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::path::Path;
+
+/// Read-only memory-mapped file with an alignment guarantee.
+///
+/// Mappings returned by the OS always start at a page boundary, so the contents of the file
+/// are available as an [`AlignedSlice<Page>`](`alignment::Page`) without copying.
+/// The file is unmapped when this structure is dropped.
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+#[derive(Debug)]
+pub struct AlignedMmap<A: Alignment> {
+    mmap: Mmap,
+    phantom: PhantomData<A>,
+}
+
+impl AlignedBytes<alignment::Page> {
+    /// Map the file at `path` into memory read-only.
+    ///
+    /// # Errors
+    /// If the file cannot be opened or mapped.
+    ///
+    /// # Safety
+    /// Same as for [`Mmap::map`] &ndash; the file must not be modified, truncated,
+    /// or otherwise changed by this or another process while it is mapped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::AlignedBytes;
+    ///
+    /// let path = std::env::temp_dir().join("aligners_from_mmap_doctest");
+    /// std::fs::write(&path, [1, 2, 3]).unwrap();
+    ///
+    /// // SAFETY: the file is not modified while it is mapped.
+    /// let mmap = unsafe { AlignedBytes::from_mmap(&path) }.unwrap();
+    ///
+    /// assert_eq!(&**mmap, [1, 2, 3]);
+    /// # drop(mmap);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+    #[inline]
+    pub unsafe fn from_mmap<P: AsRef<Path>>(path: P) -> io::Result<AlignedMmap<alignment::Page>> {
+        let file = File::open(path)?;
+
+        // SAFETY:
+        // Guaranteed by the caller.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        debug_assert!(
+            mmap.is_empty() || (mmap.as_ptr() as usize).is_multiple_of(alignment::Page::size()),
+            "memory map returned by the OS is not page aligned"
+        );
+
+        Ok(AlignedMmap {
+            mmap,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<A: Alignment> Deref for AlignedMmap<A> {
+    type Target = AlignedSlice<A>;
+
+    #[inline]
+    fn deref(&self) -> &AlignedSlice<A> {
+        // Empty mappings may not be backed by an actual page.
+        if self.mmap.is_empty() {
+            return Default::default();
+        }
+
+        let slice: &[u8] = &self.mmap;
+
+        // SAFETY:
+        // Non-empty mappings start at a page boundary, which is the guarantee of `A` (`Page`).
+        // transmute is safe because of AlignedSlice's repr(transparent).
+        unsafe { std::mem::transmute::<&[u8], &AlignedSlice<A>>(slice) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alignment::{self, Alignment};
+    use crate::test::assert_aligned;
+    use crate::AlignedBytes;
+
+    #[test]
+    fn mapped_file_is_page_aligned() {
+        let path = std::env::temp_dir().join(format!("aligners_mmap_test_{}", std::process::id()));
+        let contents = (0..=255).cycle().take(10000).collect::<Vec<u8>>();
+        std::fs::write(&path, &contents).unwrap();
+
+        // SAFETY: the file is not modified while it is mapped.
+        let mmap = unsafe { AlignedBytes::from_mmap(&path) }.unwrap();
+
+        assert_aligned(mmap.as_ptr(), alignment::Page::size());
+        assert_eq!(&**mmap, contents);
+
+        drop(mmap);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -23,8 +23,8 @@ impl<A: Alignment> AlignedBlock<alignment::Twice<A>> {
         // Both transmutes are safe. The alignment guarantee is obviously upheld, since slice is aligned
         // to twice `A` and the bytes are contiguous.
         unsafe {
-            let block1 = mem::transmute(slice1);
-            let block2 = mem::transmute(slice2);
+            let block1 = mem::transmute::<&[u8], &AlignedBlock<A>>(slice1);
+            let block2 = mem::transmute::<&[u8], &AlignedBlock<A>>(slice2);
 
             (block1, block2)
         }
@@ -47,11 +47,9 @@ mod test {
 
         for (block, ex) in iter.zip(expected) {
             let (block1, block2) = block.halves();
-            let slice1: &[u8] = block1;
-            let slice2: &[u8] = block2;
 
-            assert_eq!(slice1, ex.0);
-            assert_eq!(slice2, ex.1);
+            assert_eq!(block1, &ex.0);
+            assert_eq!(block2, &ex.1);
             assert_aligned(block1.as_ptr(), 2);
             assert_aligned(block2.as_ptr(), 2);
         }
@@ -65,11 +63,9 @@ mod test {
 
         for (block, ex) in iter.zip(expected) {
             let (block1, block2) = block.halves();
-            let slice1: &[u8] = block1;
-            let slice2: &[u8] = block2;
 
-            assert_eq!(slice1, ex.0);
-            assert_eq!(slice2, ex.1);
+            assert_eq!(block1, ex.0);
+            assert_eq!(block2, ex.1);
             assert_aligned(block1.as_ptr(), 2);
             assert_aligned(block2.as_ptr(), 2);
         }
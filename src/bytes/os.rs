@@ -0,0 +1,370 @@
+use crate::alignment::{self, Alignment};
+use crate::bytes::AlignedBytes;
+use crate::slice::AlignedSlice;
+use std::fmt::Debug;
+use std::io;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+
+/// Bytes aligned to a boundary represented by `A` whose memory is protected against writes.
+///
+/// Created with [`AlignedBytes::protect_read_only`]. The bytes can only be read through
+/// [`Deref`], so any write to them is rejected at compile time:
+///
+/// ```rust,compile_fail
+/// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+/// let bytes = AlignedBytes::<alignment::Page>::new_zeroed(alignment::Page::size());
+/// let mut read_only = bytes.protect_read_only().unwrap();
+///
+/// read_only[0] = 42;
+/// ```
+///
+/// Write access is restored when the bytes are dropped or converted back with
+/// [`into_writable`](`ReadOnlyAlignedBytes::into_writable`).
+#[cfg_attr(docsrs, doc(cfg(all(feature = "libc", unix))))]
+pub struct ReadOnlyAlignedBytes<A: Alignment> {
+    // Dropped manually, as it must not be deallocated while still read-only.
+    bytes: ManuallyDrop<AlignedBytes<A>>,
+}
+
+impl<A: Alignment> AlignedBytes<A> {
+    // Operations on whole pages are only valid when the bytes start at a page boundary.
+    fn ensure_page_aligned(&self) -> io::Result<()> {
+        if A::size() < alignment::Page::size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the operation requires alignment to at least a page boundary",
+            ));
+        }
+
+        Ok(())
+    }
+
+    // mprotect works on whole pages, so the bytes must span them exactly,
+    // lest the protection leaks to memory outside of the allocation.
+    fn ensure_whole_pages(&self) -> io::Result<()> {
+        self.ensure_page_aligned()?;
+
+        if !self.len().is_multiple_of(alignment::Page::size()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the operation requires the length to be a multiple of the page size",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn mprotect(&self, protection: libc::c_int) -> io::Result<()> {
+        self.ensure_whole_pages()?;
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        // SAFETY:
+        // The pointer is page aligned and the length is a multiple of the page size,
+        // so the affected pages are exactly those of the allocation owned by `self`.
+        let result =
+            unsafe { libc::mprotect(self.as_ptr() as *mut libc::c_void, self.len(), protection) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Make the memory of the bytes read-only, so that any write to it causes a fault.
+    ///
+    /// The bytes are consumed and can only be read through the returned wrapper until
+    /// they are converted back with [`ReadOnlyAlignedBytes::into_writable`].
+    ///
+    /// # Errors
+    /// If [`A::size()`](`Alignment::size`) is less than the page size, the length is not a multiple
+    /// of the page size, or the `mprotect` call fails. The bytes are returned alongside the error.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+    /// let bytes = AlignedBytes::<alignment::Page>::new_zeroed(alignment::Page::size());
+    /// let read_only = bytes.protect_read_only().unwrap();
+    ///
+    /// assert_eq!(0, read_only[0]);
+    ///
+    /// let mut bytes = read_only.into_writable().unwrap();
+    /// bytes[0] = 42;
+    ///
+    /// assert_eq!(42, bytes[0]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "libc", unix))))]
+    #[inline]
+    pub fn protect_read_only(self) -> Result<ReadOnlyAlignedBytes<A>, (io::Error, Self)> {
+        match self.mprotect(libc::PROT_READ) {
+            Ok(()) => Ok(ReadOnlyAlignedBytes {
+                bytes: ManuallyDrop::new(self),
+            }),
+            Err(err) => Err((err, self)),
+        }
+    }
+
+    /// Create new block of bytes of given length initialized to all-zeroes,
+    /// with its memory bound to the NUMA node `node`.
+    ///
+    /// The memory policy is set with `mbind` in the strict `MPOL_BIND` mode, so the pages
+    /// are only ever allocated on the given node. Pages already touched by the allocator
+    /// are migrated there.
+    ///
+    /// Binding works on whole pages, so the last page of the allocation is bound as a whole
+    /// even if the bytes do not span all of it.
+    ///
+    /// # Errors
+    /// If [`A::size()`](`Alignment::size`) is less than the page size, or the `mbind` call fails,
+    /// for example if `node` does not exist or the kernel is built without NUMA support.
+    ///
+    /// # Panics
+    /// If allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+    /// let bytes = AlignedBytes::<alignment::Page>::new_zeroed_on_node(4096, 0).unwrap();
+    ///
+    /// assert!(bytes.iter().all(|&x| x == 0));
+    /// ```
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "libc", target_os = "linux"))))]
+    #[inline]
+    pub fn new_zeroed_on_node(size: usize, node: u32) -> io::Result<Self> {
+        // Constants from linux/mempolicy.h, not exported by libc.
+        const MPOL_BIND: libc::c_int = 2;
+        const MPOL_MF_MOVE: libc::c_uint = 1 << 1;
+        const BITS_PER_MASK_WORD: usize = libc::c_ulong::BITS as usize;
+
+        let bytes = Self::new_zeroed(size);
+        bytes.ensure_page_aligned()?;
+
+        if bytes.is_empty() {
+            return Ok(bytes);
+        }
+
+        let node = node as usize;
+        let mut node_mask = vec![0 as libc::c_ulong; node / BITS_PER_MASK_WORD + 1];
+        node_mask[node / BITS_PER_MASK_WORD] |= 1 << (node % BITS_PER_MASK_WORD);
+        // The kernel ignores the last bit of the mask, see the BUGS section of `man 2 mbind`.
+        let max_node = node_mask.len() * BITS_PER_MASK_WORD + 1;
+
+        // SAFETY:
+        // The pointer is page aligned and the range covers exactly the allocation owned by `bytes`,
+        // rounded up to whole pages by the kernel. The node mask is valid for reading
+        // `max_node - 1` bits.
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_mbind,
+                bytes.as_ptr(),
+                bytes.len(),
+                MPOL_BIND,
+                node_mask.as_ptr(),
+                max_node,
+                MPOL_MF_MOVE,
+            )
+        };
+
+        if result == 0 {
+            Ok(bytes)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Lock the pages containing the bytes in RAM, preventing them from being swapped out.
+    ///
+    /// # Errors
+    /// If [`A::size()`](`Alignment::size`) is less than the page size, or the `mlock` call fails,
+    /// for example due to insufficient privileges or exceeding `RLIMIT_MEMLOCK`.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "libc", unix))))]
+    #[inline]
+    pub fn mlock(&self) -> io::Result<()> {
+        self.ensure_page_aligned()?;
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        // SAFETY:
+        // The pointer and length describe exactly the allocation owned by `self`.
+        let result = unsafe { libc::mlock(self.as_ptr().cast(), self.len()) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Unlock the pages containing the bytes, previously locked with [`mlock`](`AlignedBytes::mlock`).
+    ///
+    /// # Errors
+    /// If [`A::size()`](`Alignment::size`) is less than the page size, or the `munlock` call fails.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "libc", unix))))]
+    #[inline]
+    pub fn munlock(&self) -> io::Result<()> {
+        self.ensure_page_aligned()?;
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        // SAFETY:
+        // The pointer and length describe exactly the allocation owned by `self`.
+        let result = unsafe { libc::munlock(self.as_ptr().cast(), self.len()) };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
+impl<A: Alignment> ReadOnlyAlignedBytes<A> {
+    /// Make the memory of the bytes readable and writable again and return them.
+    ///
+    /// # Errors
+    /// If the `mprotect` call fails. The bytes are then leaked, as memory that is still
+    /// read-only cannot be returned to the allocator.
+    #[inline]
+    pub fn into_writable(self) -> io::Result<AlignedBytes<A>> {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY:
+        // `this` is never dropped, so the bytes are taken out exactly once.
+        let bytes = unsafe { ManuallyDrop::take(&mut this.bytes) };
+
+        match bytes.mprotect(libc::PROT_READ | libc::PROT_WRITE) {
+            Ok(()) => Ok(bytes),
+            Err(err) => {
+                std::mem::forget(bytes);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<A: Alignment> Drop for ReadOnlyAlignedBytes<A> {
+    #[inline]
+    fn drop(&mut self) {
+        // The allocator may write to freed memory, so write access has to be restored first.
+        // If that fails the bytes are leaked.
+        if self
+            .bytes
+            .mprotect(libc::PROT_READ | libc::PROT_WRITE)
+            .is_ok()
+        {
+            // SAFETY:
+            // `self.bytes` is never used again, as `self` is being dropped.
+            unsafe { ManuallyDrop::drop(&mut self.bytes) }
+        }
+    }
+}
+
+impl<A: Alignment> Deref for ReadOnlyAlignedBytes<A> {
+    type Target = AlignedSlice<A>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<A: Alignment> Debug for ReadOnlyAlignedBytes<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alignment::{self, Alignment};
+    use crate::AlignedBytes;
+
+    #[test]
+    #[ignore = "requires the privilege to lock memory"]
+    fn mlock_page_succeeds() {
+        let bytes: AlignedBytes<alignment::Page> =
+            AlignedBytes::new_zeroed(alignment::Page::size());
+
+        bytes.mlock().unwrap();
+        bytes.munlock().unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    #[ignore = "requires a NUMA-enabled kernel"]
+    fn new_zeroed_on_node_zero_succeeds() {
+        let bytes: AlignedBytes<alignment::Page> =
+            AlignedBytes::new_zeroed_on_node(4 * alignment::Page::size() + 1, 0).unwrap();
+
+        assert_eq!(4 * alignment::Page::size() + 1, bytes.len());
+        assert!(bytes.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn new_zeroed_on_node_with_insufficient_alignment_is_an_error() {
+        let err = AlignedBytes::<alignment::Eight>::new_zeroed_on_node(64, 0).unwrap_err();
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+    }
+
+    #[test]
+    fn converting_back_to_writable_allows_writes() {
+        let bytes: AlignedBytes<alignment::Page> =
+            AlignedBytes::new_zeroed(2 * alignment::Page::size());
+
+        let read_only = bytes.protect_read_only().unwrap();
+        assert_eq!(0, read_only[0]);
+        let mut bytes = read_only.into_writable().unwrap();
+        bytes[0] = 42;
+
+        assert_eq!(42, bytes[0]);
+    }
+
+    #[test]
+    fn read_only_bytes_can_be_dropped() {
+        let bytes: AlignedBytes<alignment::Page> =
+            AlignedBytes::new_zeroed(alignment::Page::size());
+
+        let read_only = bytes.protect_read_only().unwrap();
+
+        drop(read_only);
+    }
+
+    #[test]
+    fn empty_bytes_can_be_protected() {
+        let bytes: AlignedBytes<alignment::Page> = AlignedBytes::new_zeroed(0);
+
+        let read_only = bytes.protect_read_only().unwrap();
+
+        assert!(read_only.is_empty());
+    }
+
+    #[test]
+    fn protecting_partial_page_is_an_error() {
+        let bytes: AlignedBytes<alignment::Page> = AlignedBytes::new_zeroed(100);
+
+        let (err, bytes) = bytes.protect_read_only().unwrap_err();
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+        assert_eq!(100, bytes.len());
+    }
+
+    #[test]
+    fn mlock_with_insufficient_alignment_is_an_error() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(64);
+
+        let err = bytes.mlock().unwrap_err();
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+    }
+}
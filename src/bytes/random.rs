@@ -0,0 +1,81 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+
+impl<A: Alignment> AlignedBytes<A> {
+    /// Create new block of bytes of given length filled with random bytes from `rng`.
+    ///
+    /// The bytes are generated directly into the aligned allocation, without going through
+    /// an intermediate unaligned buffer.
+    ///
+    /// # Panics
+    /// If allocating memory fails, or if `rng` panics while filling the bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let aligned = AlignedBytes::<alignment::TwoTo<6>>::new_random(1024, &mut rng);
+    ///
+    /// assert_eq!(aligned.len(), 1024);
+    /// assert_eq!(aligned.as_ptr() as usize % 64, 0);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[must_use]
+    #[inline]
+    pub fn new_random<R: rand::Rng + ?Sized>(size: usize, rng: &mut R) -> Self {
+        // SAFETY:
+        // All bytes are initialized right after.
+        let mut block = unsafe { Self::new(size) };
+
+        rng.fill_bytes(&mut block);
+
+        block
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alignment::{self, Alignment};
+    use crate::test::assert_aligned;
+    use crate::AlignedBytes;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn random_bytes_are_aligned() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bytes = AlignedBytes::<alignment::Page>::new_random(4096 + 17, &mut rng);
+
+        assert_eq!(4096 + 17, bytes.len());
+        assert_aligned(bytes.as_ptr(), alignment::Page::size());
+    }
+
+    #[test]
+    fn random_bytes_from_different_seeds_differ() {
+        let mut rng1 = StdRng::seed_from_u64(1);
+        let mut rng2 = StdRng::seed_from_u64(2);
+        let bytes1 = AlignedBytes::<alignment::TwoTo<6>>::new_random(256, &mut rng1);
+        let bytes2 = AlignedBytes::<alignment::TwoTo<6>>::new_random(256, &mut rng2);
+
+        assert_ne!(bytes1, bytes2);
+    }
+
+    #[test]
+    fn random_bytes_from_same_seed_are_equal() {
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        let bytes1 = AlignedBytes::<alignment::TwoTo<6>>::new_random(256, &mut rng1);
+        let bytes2 = AlignedBytes::<alignment::TwoTo<6>>::new_random(256, &mut rng2);
+
+        assert_eq!(bytes1, bytes2);
+    }
+
+    #[test]
+    fn empty_random_bytes() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bytes = AlignedBytes::<alignment::TwoTo<6>>::new_random(0, &mut rng);
+
+        assert!(bytes.is_empty());
+    }
+}
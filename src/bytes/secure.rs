@@ -0,0 +1,99 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+use crate::slice::AlignedSlice;
+use std::ops::{Deref, DerefMut};
+
+/// Owned aligned bytes that are overwritten with zeroes before being deallocated.
+///
+/// Meant for sensitive data like cryptographic keys. The zeroing uses volatile writes,
+/// so the compiler cannot optimise it away even though the memory is freed right after.
+///
+/// # Guarantees
+///
+/// Same as for [`AlignedBytes`], which is used internally to hold the bytes.
+///
+/// The [`Debug`](`std::fmt::Debug`) implementation does not print the contents.
+///
+/// # Examples
+/// ```rust
+/// use aligners::{alignment, AlignedBytes, SecureAlignedBytes};
+///
+/// let key: SecureAlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([42; 32]).into();
+///
+/// assert_eq!(key.len(), 32);
+/// assert_eq!(format!("{:?}", key), "SecureAlignedBytes { len: 32, .. }");
+/// ```
+pub struct SecureAlignedBytes<A: Alignment> {
+    bytes: AlignedBytes<A>,
+}
+
+impl<A: Alignment> SecureAlignedBytes<A> {
+    /// Create new block of bytes of given length, initialized to all-zeroes.
+    #[must_use]
+    #[inline]
+    pub fn new_zeroed(size: usize) -> Self {
+        AlignedBytes::new_zeroed(size).into()
+    }
+}
+
+impl<A: Alignment> From<AlignedBytes<A>> for SecureAlignedBytes<A> {
+    #[inline]
+    fn from(bytes: AlignedBytes<A>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<A: Alignment> Drop for SecureAlignedBytes<A> {
+    #[inline]
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+        // The inner AlignedBytes is deallocated after this.
+    }
+}
+
+impl<A: Alignment> Deref for SecureAlignedBytes<A> {
+    type Target = AlignedSlice<A>;
+
+    #[inline]
+    fn deref(&self) -> &AlignedSlice<A> {
+        &self.bytes
+    }
+}
+
+impl<A: Alignment> DerefMut for SecureAlignedBytes<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut AlignedSlice<A> {
+        &mut self.bytes
+    }
+}
+
+impl<A: Alignment> std::fmt::Debug for SecureAlignedBytes<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecureAlignedBytes")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecureAlignedBytes;
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn secure_bytes_are_aligned() {
+        let bytes: SecureAlignedBytes<alignment::TwoTo<6>> = AlignedBytes::from([1, 2, 3]).into();
+
+        assert_aligned(bytes.as_ptr(), 64);
+        assert_eq!(&**bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn dropping_empty_secure_bytes() {
+        let bytes: SecureAlignedBytes<alignment::TwoTo<6>> = SecureAlignedBytes::new_zeroed(0);
+
+        drop(bytes);
+    }
+}
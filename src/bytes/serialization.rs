@@ -0,0 +1,87 @@
+use crate::alignment::Alignment;
+use crate::bytes::{AlignedBytes, AlignedVec};
+use serde::de::{SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+// Upper bound on preallocation driven by an untrusted `size_hint`.
+const MAX_PREALLOCATION: usize = 4096;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<A: Alignment> Serialize for AlignedBytes<A> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, A: Alignment> Deserialize<'de> for AlignedBytes<A> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(AlignedBytesVisitor {
+            phantom: PhantomData,
+        })
+    }
+}
+
+struct AlignedBytesVisitor<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<'de, A: Alignment> Visitor<'de> for AlignedBytesVisitor<A> {
+    type Value = AlignedBytes<A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        // Deserialized data is not aligned, so it always has to be copied.
+        Ok(v.into())
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let capacity = std::cmp::min(seq.size_hint().unwrap_or(0), MAX_PREALLOCATION);
+        let mut vec = AlignedVec::<A>::with_capacity(capacity);
+
+        while let Some(byte) = seq.next_element()? {
+            vec.push(byte);
+        }
+
+        Ok(vec.into_aligned_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn round_trips_through_bincode() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(100, |i| i as u8);
+
+        let serialized = bincode::serialize(&bytes).unwrap();
+        let deserialized: AlignedBytes<alignment::TwoTo<6>> =
+            bincode::deserialize(&serialized).unwrap();
+
+        assert_aligned(deserialized.as_ptr(), 64);
+        assert_eq!(bytes, deserialized);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::from([1, 2, 3]);
+
+        let serialized = serde_json::to_string(&bytes).unwrap();
+        let deserialized: AlignedBytes<alignment::TwoTo<6>> =
+            serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!("[1,2,3]", serialized);
+        assert_aligned(deserialized.as_ptr(), 64);
+        assert_eq!(bytes, deserialized);
+    }
+}
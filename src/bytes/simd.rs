@@ -7,9 +7,7 @@ mod tests {
     #[test]
     fn is_block_aligned_when_created_from_unaligned_slice() {
         let alignment_size = alignment::SimdBlock::size();
-        let slice: &[u8] = &std::iter::repeat(42)
-            .take(alignment_size)
-            .collect::<Vec<_>>();
+        let slice: &[u8] = &std::iter::repeat_n(42, alignment_size).collect::<Vec<_>>();
         let misalignment = slice.as_ptr() as usize % alignment_size;
         let source = if misalignment > 0 { slice } else { &slice[1..] };
         let bytes = AlignedBytes::<alignment::SimdBlock>::from(source);
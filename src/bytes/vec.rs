@@ -0,0 +1,1175 @@
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+use crate::slice::AlignedSlice;
+use std::alloc::Layout;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+mod drain;
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use drain::*;
+
+/// Error returned by [`AlignedVec::try_reserve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The allocator failed to provide the memory.
+    AllocError {
+        /// Layout of the allocation that failed.
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for TryReserveError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => write!(f, "capacity overflow"),
+            Self::AllocError { layout } => write!(
+                f,
+                "memory allocation of {} bytes aligned to {} failed",
+                layout.size(),
+                layout.align()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
+
+/// Growable bytes aligned to a boundary represented by `A`.
+///
+/// This is the aligned counterpart of a [`Vec<u8>`]. It owns the bytes, grows its
+/// allocation as needed and deallocates it on drop. A new buffer does not allocate
+/// until the first byte is added.
+///
+/// # Guarantees
+///
+/// It is guaranteed that the bytes allocated in this structure are aligned
+/// to an [`A::size()`](`Alignment::size`) byte boundary. This is retained across
+/// reallocations, so the pointer obtained by [`as_ptr`](`AlignedVec::as_ptr`) is always
+/// divisible by [`A::size()`](`Alignment::size`), but it may change after the buffer grows.
+pub struct AlignedVec<A: Alignment> {
+    bytes_ptr: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    phantom: std::marker::PhantomData<A>,
+}
+
+// SAFETY:
+// Same as for `AlignedBytes`, the buffer is exclusively owned like in a `Vec<u8>`.
+unsafe impl<A: Alignment> Send for AlignedVec<A> {}
+
+// SAFETY:
+// Same as for `AlignedBytes`, growing requires `&mut self` and there is no interior mutability.
+unsafe impl<A: Alignment> Sync for AlignedVec<A> {}
+
+impl<A: Alignment> AlignedVec<A> {
+    /// Create a new, empty buffer. This does not allocate.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bytes_ptr: AlignedBytes::<A>::dangling(),
+            len: 0,
+            capacity: 0,
+            phantom: std::marker::PhantomData {},
+        }
+    }
+
+    /// Create a new, empty buffer with space for exactly `capacity` bytes.
+    ///
+    /// # Panics
+    /// If `capacity` exceeds `isize::MAX` or allocating memory fails.
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+
+        if capacity > 0 {
+            vec.grow_to(capacity);
+        }
+
+        vec
+    }
+
+    /// Return the size of the alignment in bytes.
+    ///
+    /// ## Note
+    /// This does not reflect the actual maximal alignment,
+    /// only the guarantee provided by `A`, which may be lower than
+    /// the actual alignment.
+    #[must_use]
+    #[inline(always)]
+    pub fn alignment_size(&self) -> usize {
+        A::size()
+    }
+
+    /// Return the number of bytes in the buffer.
+    #[must_use]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return whether the buffer contains no bytes.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the number of bytes the buffer can hold without reallocating.
+    #[must_use]
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the pointer to the beginning of the aligned buffer.
+    #[must_use]
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.bytes_ptr.as_ptr()
+    }
+
+    /// Get a `mut` pointer to the beginning of the aligned buffer.
+    #[must_use]
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.bytes_ptr.as_ptr()
+    }
+
+    /// Reserve capacity for at least `additional` more bytes.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+
+        if required <= self.capacity {
+            return;
+        }
+
+        // Capacity never exceeds `isize::MAX`, so doubling cannot overflow.
+        let new_capacity = std::cmp::max(required, self.capacity * 2);
+        self.grow_to(new_capacity);
+    }
+
+    /// Try to reserve capacity for at least `additional` more bytes.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// This is the fallible version of [`reserve`](`AlignedVec::reserve`),
+    /// which returns an error instead of panicking or aborting.
+    ///
+    /// # Errors
+    /// [`TryReserveError::CapacityOverflow`] if the new capacity exceeds `isize::MAX`,
+    /// and [`TryReserveError::AllocError`] if allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{alignment, AlignedVec, TryReserveError};
+    /// let mut vec: AlignedVec<alignment::Eight> = AlignedVec::new();
+    ///
+    /// assert!(vec.try_reserve(100).is_ok());
+    /// assert!(vec.capacity() >= 100);
+    /// assert_eq!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    /// ```
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        // Capacity never exceeds `isize::MAX`, so doubling cannot overflow.
+        let new_capacity = std::cmp::max(required, self.capacity * 2);
+        self.try_grow_to(new_capacity)
+    }
+
+    /// Append a single byte to the end of the buffer.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        self.reserve(1);
+
+        // SAFETY:
+        // After `reserve` we have `self.len < self.capacity`, so the write is within the allocation.
+        unsafe { self.bytes_ptr.as_ptr().add(self.len).write(byte) };
+        self.len += 1;
+    }
+
+    /// Insert a byte at position `index`, shifting all bytes after it to the right.
+    ///
+    /// # Panics
+    /// If `index > len`, or if the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn insert(&mut self, index: usize, byte: u8) {
+        let len = self.len;
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        self.reserve(1);
+
+        // SAFETY:
+        // After `reserve` there is space for `len + 1` bytes. We shift the `len - index` bytes
+        // starting at `index` by one, so both source and destination ranges are within the allocation.
+        // `ptr::copy` handles the overlap.
+        unsafe {
+            let ptr = self.bytes_ptr.as_ptr().add(index);
+            std::ptr::copy(ptr, ptr.add(1), len - index);
+            ptr.write(byte);
+        }
+        self.len += 1;
+    }
+
+    /// Remove and return the byte at position `index`, shifting all bytes after it to the left.
+    ///
+    /// # Panics
+    /// If `index >= len`.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> u8 {
+        let len = self.len;
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+
+        // SAFETY:
+        // `index < len`, so the read is of an initialized byte. We then shift the `len - index - 1` bytes
+        // after it by one to the left, so both source and destination ranges are within the initialized bytes.
+        // `ptr::copy` handles the overlap.
+        unsafe {
+            let ptr = self.bytes_ptr.as_ptr().add(index);
+            let byte = ptr.read();
+            std::ptr::copy(ptr.add(1), ptr, len - index - 1);
+            self.len -= 1;
+
+            byte
+        }
+    }
+
+    /// Remove and return the byte at position `index`, replacing it with the last byte of the buffer.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    /// If `index >= len`.
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> u8 {
+        let len = self.len;
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+
+        self.swap(index, len - 1);
+        self.len -= 1;
+
+        // SAFETY:
+        // The byte at the old `len - 1` is initialized and within the allocation.
+        unsafe { self.bytes_ptr.as_ptr().add(self.len).read() }
+    }
+
+    /// Copy all bytes from `other` to the end of the buffer.
+    ///
+    /// The required capacity is reserved up front, so this causes at most one reallocation.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[u8]) {
+        self.reserve(other.len());
+
+        // SAFETY:
+        // - src is valid for reading `other.len()` bytes.
+        // - dst is valid for writing `other.len()` bytes, since we reserved that much space after `self.len`.
+        //   If both are empty the dangling pointer is valid for a zero-length copy.
+        // - The regions do not overlap, since `other` is borrowed immutably while `self` is borrowed mutably,
+        //   so it cannot point into our allocation.
+        // - Both pointers are properly aligned, since proper alignment for `u8` is 1.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                self.bytes_ptr.as_ptr().add(self.len),
+                other.len(),
+            )
+        };
+        self.len += other.len();
+    }
+
+    /// Move all bytes of `other` to the end of the buffer, leaving `other` empty.
+    ///
+    /// The capacity of `other` is not released.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn append(&mut self, other: &mut AlignedVec<A>) {
+        self.extend_from_slice(other);
+        other.clear();
+    }
+
+    /// Split the buffer in two at the given index.
+    ///
+    /// Returns a newly allocated buffer containing the bytes `[at, len)`,
+    /// and leaves `self` with the bytes `[0, at)`. The tail is copied into a fresh allocation,
+    /// so that it starts at an [`A::size()`](`Alignment::size`) boundary regardless of `at`.
+    ///
+    /// # Panics
+    /// If `at > len`, or if allocating memory fails.
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> AlignedVec<A> {
+        let len = self.len;
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+
+        let mut other = AlignedVec::with_capacity(len - at);
+        other.extend_from_slice(&self[at..]);
+        self.truncate(at);
+
+        other
+    }
+
+    /// Remove all bytes from the buffer.
+    ///
+    /// This does not release the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Shorten the buffer to `new_len` bytes.
+    /// Does nothing if `new_len` is not less than the current length.
+    ///
+    /// This does not release the capacity.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.len = new_len;
+        }
+    }
+
+    /// Force the length of the buffer to `new_len`.
+    ///
+    /// This is useful after initializing the spare capacity manually, for example via
+    /// [`as_mut_ptr`](`AlignedVec::as_mut_ptr`).
+    ///
+    /// # Safety
+    /// - `new_len` must be less than or equal to [`capacity`](`AlignedVec::capacity`).
+    /// - The bytes at `old_len..new_len` must be initialized.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+    }
+
+    /// Resize the buffer to `new_len` bytes.
+    ///
+    /// If the buffer grows, the new bytes are set to `value`.
+    /// If it shrinks, this is equivalent to [`truncate`](`AlignedVec::truncate`).
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        let additional = new_len - self.len;
+        self.reserve(additional);
+
+        // SAFETY:
+        // We reserved `additional` bytes after `self.len`, so the whole written range is within the allocation.
+        unsafe {
+            std::ptr::write_bytes(self.bytes_ptr.as_ptr().add(self.len), value, additional);
+        }
+        self.len = new_len;
+    }
+
+    /// Resize the buffer to `new_len` bytes.
+    ///
+    /// If the buffer grows, the new bytes are set to consecutive results of calling `f`.
+    /// If it shrinks, this is equivalent to [`truncate`](`AlignedVec::truncate`).
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    #[inline]
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> u8,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        while self.len < new_len {
+            self.push(f());
+        }
+    }
+
+    /// Append `pad` bytes until the length of the buffer reaches `target_len`.
+    ///
+    /// Unlike [`resize`](`AlignedVec::resize`), this never shrinks the buffer &ndash; it does
+    /// nothing if the buffer is already at least `target_len` bytes long.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedVec, alignment};
+    /// let mut vec = AlignedVec::<alignment::Eight>::new();
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// vec.pad_to(6, 0);
+    ///
+    /// assert_eq!(&vec[..], [1, 2, 3, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn pad_to(&mut self, target_len: usize, pad: u8) {
+        if target_len > self.len {
+            self.resize(target_len, pad);
+        }
+    }
+
+    /// Append `fill` bytes until the length of the buffer is a multiple of [`A::size()`](`Alignment::size`).
+    ///
+    /// Afterwards [`iter_blocks`](`AlignedSlice::iter_blocks`) yields only full blocks, which is
+    /// the same guarantee that [`AlignedBytes::new_padded`] gives. The padded length is
+    /// [`AlignedBytes::padded_len`] of the current length.
+    ///
+    /// # Panics
+    /// If the new capacity exceeds `isize::MAX` or allocating memory fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedVec, alignment};
+    /// let mut vec = AlignedVec::<alignment::Four>::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// vec.grow_to_block_multiple(0);
+    ///
+    /// assert_eq!(&vec[..], [1, 2, 3, 4, 5, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn grow_to_block_multiple(&mut self, fill: u8) {
+        self.pad_to(AlignedBytes::<A>::padded_len(self.len), fill);
+    }
+
+    /// Retain only the bytes for which `f` returns `true`, removing all others.
+    ///
+    /// This works in place in a single pass and preserves the order of the retained bytes.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let mut write = 0;
+
+        for read in 0..self.len {
+            let byte = self[read];
+
+            if f(byte) {
+                self[write] = byte;
+                write += 1;
+            }
+        }
+
+        self.len = write;
+    }
+
+    /// Remove consecutive repeated bytes, leaving only the first of each run.
+    ///
+    /// This works in place in a single pass.
+    #[inline]
+    pub fn dedup(&mut self) {
+        if self.len <= 1 {
+            return;
+        }
+
+        let mut write = 1;
+
+        for read in 1..self.len {
+            let byte = self[read];
+
+            if byte != self[write - 1] {
+                self[write] = byte;
+                write += 1;
+            }
+        }
+
+        self.len = write;
+    }
+
+    /// Convert the buffer into [`AlignedBytes`] of exactly [`len`](`AlignedVec::len`) bytes.
+    ///
+    /// If there is spare capacity, the allocation is first shrunk to fit the bytes.
+    /// The allocation is then handed over to the result without copying.
+    ///
+    /// # Panics
+    /// If shrinking the allocation fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{alignment::{self, Alignment}, AlignedBytes, AlignedVec};
+    /// let mut vec: AlignedVec<alignment::Eight> = AlignedVec::with_capacity(100);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = vec.into_aligned_bytes();
+    ///
+    /// assert_eq!(bytes, [1, 2, 3]);
+    /// assert_eq!(bytes.as_ptr() as usize % alignment::Eight::size(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_aligned_bytes(mut self) -> AlignedBytes<A> {
+        if self.len == 0 {
+            return AlignedBytes::default();
+        }
+
+        if self.capacity > self.len {
+            let old_layout = AlignedBytes::<A>::get_layout(self.capacity);
+            let new_layout = AlignedBytes::<A>::get_layout(self.len);
+            // SAFETY:
+            // - `bytes_ptr` was allocated with the global allocator using `old_layout`,
+            //   since the layout is constructed with the same function from the current capacity.
+            // - `self.len` is non-zero and less than the current capacity, so it does not overflow `isize`.
+            // `realloc` retains the alignment of `old_layout`, which is `A::size()`.
+            let raw_ptr =
+                unsafe { std::alloc::realloc(self.bytes_ptr.as_ptr(), old_layout, self.len) };
+
+            self.bytes_ptr =
+                NonNull::new(raw_ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(new_layout));
+            self.capacity = self.len;
+        }
+
+        // The allocation now has exactly the layout `AlignedBytes::get_layout(self.len)`.
+        let bytes = AlignedBytes::from_allocation(
+            self.bytes_ptr,
+            self.len,
+            super::AllocationSource::Aligned,
+        );
+        // The ownership of the allocation is transferred to `bytes`.
+        std::mem::forget(self);
+
+        bytes
+    }
+
+    fn grow_to(&mut self, new_capacity: usize) {
+        match self.try_grow_to(new_capacity) {
+            Ok(()) => (),
+            Err(TryReserveError::CapacityOverflow) => panic!(
+                "cannot allocate more than `isize::MAX` bytes, attempted to allocate {new_capacity}"
+            ),
+            Err(TryReserveError::AllocError { layout }) => std::alloc::handle_alloc_error(layout),
+        }
+    }
+
+    fn try_grow_to(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+        if new_capacity > (isize::MAX as usize) {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        let new_layout = Layout::from_size_align(new_capacity, A::size())
+            .map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        let raw_ptr = if self.capacity == 0 {
+            // SAFETY:
+            // Layout is of non-zero size, since `new_capacity` is greater than the current capacity.
+            unsafe { std::alloc::alloc(new_layout) }
+        } else {
+            let old_layout = AlignedBytes::<A>::get_layout(self.capacity);
+            // SAFETY:
+            // - `bytes_ptr` was allocated with the global allocator using `old_layout`,
+            //   since the layout is constructed with the same function from the current capacity.
+            // - `new_capacity` is non-zero and, rounded up to `A::size()`, does not overflow `isize`,
+            //   which is checked when constructing `new_layout`.
+            // `realloc` retains the alignment of `old_layout`, which is `A::size()`.
+            unsafe { std::alloc::realloc(self.bytes_ptr.as_ptr(), old_layout, new_capacity) }
+        };
+
+        // On failure the old allocation is left untouched, so `self` remains valid.
+        self.bytes_ptr =
+            NonNull::new(raw_ptr).ok_or(TryReserveError::AllocError { layout: new_layout })?;
+        self.capacity = new_capacity;
+
+        debug_assert!(crate::is_aligned_to(self.bytes_ptr.as_ptr(), A::size()));
+
+        Ok(())
+    }
+}
+
+impl<A: Alignment> Drop for AlignedVec<A> {
+    #[inline]
+    fn drop(&mut self) {
+        use std::alloc::dealloc;
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        let layout = AlignedBytes::<A>::get_layout(self.capacity);
+
+        // SAFETY:
+        // `ptr` is allocated in `grow_to` with a layout constructed using the same function
+        // from the current capacity, so it will be the same.
+        unsafe { dealloc(self.bytes_ptr.as_ptr(), layout) }
+    }
+}
+
+impl<A: Alignment> Default for AlignedVec<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Alignment> Extend<u8> for AlignedVec<A> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
+
+        for byte in iter {
+            self.push(byte);
+        }
+    }
+}
+
+impl<'a, A: Alignment> Extend<&'a u8> for AlignedVec<A> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<A: Alignment> std::io::Write for AlignedVec<A> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<A: Alignment> FromIterator<u8> for AlignedVec<A> {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+
+        vec
+    }
+}
+
+impl<A: Alignment> Deref for AlignedVec<A> {
+    type Target = AlignedSlice<A>;
+
+    #[inline]
+    fn deref(&self) -> &AlignedSlice<A> {
+        // SAFETY:
+        // - the `data` pointer is a `NonNull` pointer to an allocation with at least `self.len`
+        //   initialized bytes, or the dangling aligned pointer if `self.len` is zero;
+        // - it is properly aligned since proper alignment for `u8` is 1;
+        // - transmute is safe because of AlignedSlice's repr(transparent).
+        unsafe {
+            let slice = std::slice::from_raw_parts(self.as_ptr(), self.len);
+            std::mem::transmute::<&[u8], &AlignedSlice<A>>(slice)
+        }
+    }
+}
+
+impl<A: Alignment> DerefMut for AlignedVec<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut AlignedSlice<A> {
+        // SAFETY:
+        // Same as for `deref`. The returned borrow is tied to the mutable borrow of `self`,
+        // so it is the only valid reference to the underlying bytes.
+        unsafe {
+            let slice = std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len);
+            std::mem::transmute::<&mut [u8], &mut AlignedSlice<A>>(slice)
+        }
+    }
+}
+
+impl<A: Alignment> std::fmt::Debug for AlignedVec<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deref: &AlignedSlice<A> = self;
+        std::fmt::Debug::fmt(deref, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedVec, TryReserveError};
+
+    #[test]
+    fn io_copy_into_vec_preserves_contents_and_alignment() {
+        let source = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+
+        let copied = std::io::copy(&mut std::io::Cursor::new(&source), &mut vec).unwrap();
+
+        assert_eq!(1000, copied);
+        assert_aligned(vec.as_ptr(), 32);
+        assert_eq!(&vec[..], source);
+    }
+
+    #[test]
+    fn write_macro_appends_formatted_bytes() {
+        use std::io::Write;
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+
+        write!(vec, "{}-{}", 4, 2).unwrap();
+
+        assert_eq!(&vec[..], b"4-2");
+    }
+
+    #[test]
+    fn growing_across_reallocations_stays_aligned() {
+        let mut vec: AlignedVec<alignment::TwoTo<6>> = AlignedVec::new();
+        let mut capacity = vec.capacity();
+
+        for i in 0..2000 {
+            vec.push(i as u8);
+
+            if vec.capacity() != capacity {
+                capacity = vec.capacity();
+                assert_aligned(vec.as_ptr(), 64);
+                assert!(vec.iter().enumerate().all(|(j, &x)| x == j as u8));
+            }
+        }
+
+        assert_eq!(2000, vec.len());
+        assert_aligned(vec.as_ptr(), 64);
+    }
+
+    #[test]
+    fn try_reserve_grows_and_stays_aligned() {
+        let mut vec: AlignedVec<alignment::TwoTo<6>> = (0..10).collect();
+
+        vec.try_reserve(1000).unwrap();
+
+        assert!(vec.capacity() >= 1010);
+        assert_eq!(&vec[..], (0..10).collect::<Vec<_>>());
+        assert_aligned(vec.as_ptr(), 64);
+    }
+
+    #[test]
+    fn try_reserve_with_capacity_overflow_fails_and_keeps_contents() {
+        let mut vec: AlignedVec<alignment::TwoTo<6>> = (0..10).collect();
+        let capacity = vec.capacity();
+
+        assert_eq!(
+            vec.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(
+            vec.try_reserve(isize::MAX as usize),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(capacity, vec.capacity());
+        assert_eq!(&vec[..], (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_aligned_bytes_shrinks_spare_capacity() {
+        let mut vec: AlignedVec<alignment::TwoTo<6>> = AlignedVec::with_capacity(1000);
+        vec.extend(0..100);
+
+        let bytes = vec.into_aligned_bytes();
+
+        assert_eq!(100, bytes.len());
+        assert_eq!(bytes, (0..100).collect::<Vec<u8>>());
+        assert_aligned(bytes.as_ptr(), 64);
+    }
+
+    #[test]
+    fn into_aligned_bytes_with_exact_capacity_does_not_reallocate() {
+        let mut vec: AlignedVec<alignment::TwoTo<6>> = AlignedVec::with_capacity(100);
+        vec.extend(0..100);
+        let ptr = vec.as_ptr();
+
+        let bytes = vec.into_aligned_bytes();
+
+        assert_eq!(ptr, bytes.as_ptr());
+        assert_eq!(bytes, (0..100).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn into_aligned_bytes_of_empty_vec_with_capacity() {
+        let vec: AlignedVec<alignment::TwoTo<6>> = AlignedVec::with_capacity(100);
+
+        let bytes = vec.into_aligned_bytes();
+
+        assert!(bytes.is_empty());
+        assert_aligned(bytes.as_ptr(), 64);
+    }
+
+    #[test]
+    fn empty_vec_is_aligned() {
+        let empty: AlignedVec<alignment::Eight> = AlignedVec::new();
+        assert_aligned(empty.as_ptr(), 8);
+    }
+
+    #[test]
+    fn extend_from_slice_on_empty_vec_with_empty_slice_does_not_allocate() {
+        let mut vec: AlignedVec<alignment::Eight> = AlignedVec::new();
+        vec.extend_from_slice(&[]);
+
+        assert_eq!(0, vec.capacity());
+        assert_aligned(vec.as_ptr(), 8);
+    }
+
+    #[test]
+    fn extend_from_slice_with_one_mebibyte() {
+        let slice = (0..1024 * 1024).map(|i| i as u8).collect::<Vec<u8>>();
+        let mut vec: AlignedVec<alignment::TwoTo<7>> = AlignedVec::new();
+        vec.push(42);
+        vec.extend_from_slice(&slice);
+
+        assert_eq!(42, vec[0]);
+        assert_eq!(&vec[1..], slice);
+        assert_aligned(vec.as_ptr(), 128);
+    }
+
+    #[test]
+    fn extend_with_empty_iterator_does_not_allocate() {
+        let mut vec: AlignedVec<alignment::Eight> = AlignedVec::new();
+        vec.extend(std::iter::empty::<u8>());
+
+        assert_eq!(0, vec.capacity());
+        assert_aligned(vec.as_ptr(), 8);
+    }
+
+    #[test]
+    fn extend_grows_and_stays_aligned() {
+        let mut vec: AlignedVec<alignment::TwoTo<7>> = AlignedVec::new();
+        vec.extend(0..=200);
+        vec.extend(&[201, 202]);
+        vec.extend((203..=255).filter(|_| true));
+
+        assert_eq!(&vec[..], (0..=255).collect::<Vec<u8>>());
+        assert_aligned(vec.as_ptr(), 128);
+    }
+
+    #[test]
+    fn collect_from_iterator() {
+        let vec: AlignedVec<alignment::TwoTo<7>> = (0..=255).collect();
+
+        assert_eq!(&vec[..], (0..=255).collect::<Vec<u8>>());
+        assert_aligned(vec.as_ptr(), 128);
+    }
+
+    #[test]
+    fn truncate_below_length_keeps_capacity() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let capacity = vec.capacity();
+        vec.truncate(4);
+
+        assert_eq!(&vec[..], [0, 1, 2, 3]);
+        assert_eq!(capacity, vec.capacity());
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn truncate_above_length_is_noop() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let capacity = vec.capacity();
+        vec.truncate(20);
+
+        assert_eq!(&vec[..], (0..10).collect::<Vec<_>>());
+        assert_eq!(capacity, vec.capacity());
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let capacity = vec.capacity();
+        vec.clear();
+
+        assert!(vec.is_empty());
+        assert_eq!(capacity, vec.capacity());
+    }
+
+    #[test]
+    fn set_len_after_initializing_spare_capacity() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::with_capacity(4);
+
+        // SAFETY:
+        // We reserved 4 bytes and initialize all of them before setting the length.
+        unsafe {
+            for i in 0..4 {
+                vec.as_mut_ptr().add(i).write(i as u8);
+            }
+            vec.set_len(4);
+        }
+
+        assert_eq!(&vec[..], [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn resize_from_empty() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.resize(3, 7);
+
+        assert_eq!(&vec[..], [7, 7, 7]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn resize_to_same_length_does_not_reallocate() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::with_capacity(4);
+        vec.extend_from_slice(&[1, 2, 3, 4]);
+        let ptr = vec.as_ptr();
+        vec.resize(4, 0);
+
+        assert_eq!(&vec[..], [1, 2, 3, 4]);
+        assert_eq!(4, vec.capacity());
+        assert_eq!(ptr, vec.as_ptr());
+    }
+
+    #[test]
+    fn resize_grow_with_reallocation() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::with_capacity(2);
+        vec.extend_from_slice(&[1, 2]);
+        vec.resize(100, 0);
+
+        assert_eq!(&vec[..2], [1, 2]);
+        assert!(vec[2..].iter().all(|&x| x == 0));
+        assert_eq!(100, vec.len());
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn resize_shrink_truncates() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        vec.resize(2, 42);
+
+        assert_eq!(&vec[..], [0, 1]);
+    }
+
+    #[test]
+    fn resize_with_grow_with_reallocation() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::with_capacity(2);
+        let mut next = 0;
+        vec.resize_with(100, || {
+            next += 1;
+            next
+        });
+
+        assert_eq!(&vec[..], (1..=100).collect::<Vec<_>>());
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn insert_at_front_middle_and_end() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[2, 4]);
+        vec.insert(0, 1);
+        vec.insert(2, 3);
+        vec.insert(4, 5);
+
+        assert_eq!(&vec[..], [1, 2, 3, 4, 5]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn insert_out_of_bounds() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2]);
+        vec.insert(3, 3);
+    }
+
+    #[test]
+    fn remove_from_front_middle_and_end() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(1, vec.remove(0));
+        assert_eq!(3, vec.remove(1));
+        assert_eq!(5, vec.remove(2));
+        assert_eq!(&vec[..], [2, 4]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 2) should be < len (is 2)")]
+    fn remove_out_of_bounds() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2]);
+        vec.remove(2);
+    }
+
+    #[test]
+    fn swap_remove_from_middle_moves_last_byte() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(2, vec.swap_remove(1));
+        assert_eq!(&vec[..], [1, 5, 3, 4]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 0) should be < len (is 0)")]
+    fn swap_remove_out_of_bounds() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.swap_remove(0);
+    }
+
+    #[test]
+    fn retain_filters_out_zeroes() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[0, 1, 0, 0, 2, 3, 0, 4, 0]);
+        let ptr = vec.as_ptr();
+        vec.retain(|x| x != 0);
+
+        assert_eq!(&vec[..], [1, 2, 3, 4]);
+        assert_eq!(ptr, vec.as_ptr());
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn append_moves_all_bytes() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..3).collect();
+        let mut other: AlignedVec<alignment::TwoTo<5>> = (3..50).collect();
+        vec.append(&mut other);
+
+        assert_eq!(&vec[..], (0..50).collect::<Vec<_>>());
+        assert!(other.is_empty());
+        assert_aligned(vec.as_ptr(), 32);
+        assert_aligned(other.as_ptr(), 32);
+    }
+
+    #[test]
+    fn split_off_copies_tail_into_aligned_buffer() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let other = vec.split_off(3);
+
+        assert_eq!(&vec[..], [0, 1, 2]);
+        assert_eq!(&other[..], [3, 4, 5, 6, 7, 8, 9]);
+        assert_aligned(vec.as_ptr(), 32);
+        assert_aligned(other.as_ptr(), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "`at` split index (is 11) should be <= len (is 10)")]
+    fn split_off_out_of_bounds() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let _ = vec.split_off(11);
+    }
+
+    #[test]
+    fn dedup_removes_consecutive_duplicates() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 1, 2, 2, 2, 3, 1, 1]);
+        vec.dedup();
+
+        assert_eq!(&vec[..], [1, 2, 3, 1]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn dedup_edge_cases() {
+        let mut empty: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+
+        let mut all_equal: AlignedVec<alignment::TwoTo<5>> = std::iter::repeat_n(7, 10).collect();
+        all_equal.dedup();
+        assert_eq!(&all_equal[..], [7]);
+
+        let mut distinct: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        distinct.dedup();
+        assert_eq!(&distinct[..], (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn pad_to_extends_with_pad_bytes() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+
+        vec.pad_to(32, 0);
+
+        assert_eq!(32, vec.len());
+        assert_eq!(&vec[..5], [1, 2, 3, 4, 5]);
+        assert!(vec[5..].iter().all(|&x| x == 0));
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn pad_to_shorter_length_does_nothing() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = AlignedVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+
+        vec.pad_to(3, 0xFF);
+
+        assert_eq!(&vec[..], [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn grow_to_block_multiple_pads_last_block() {
+        let mut vec: AlignedVec<alignment::TwoTo<4>> = AlignedVec::new();
+        vec.extend_from_slice(&(1..=21).collect::<Vec<u8>>());
+
+        vec.grow_to_block_multiple(0xFF);
+
+        assert_eq!(0, vec.len() % 16);
+        assert_eq!(&vec[..21], (1..=21).collect::<Vec<u8>>());
+        assert!(vec[21..].iter().all(|&x| x == 0xFF));
+        assert!(vec.iter_blocks().all(|block| block.len() == 16));
+        assert_aligned(vec.as_ptr(), 16);
+    }
+
+    #[test]
+    fn grow_to_block_multiple_of_full_blocks_does_nothing() {
+        let mut vec: AlignedVec<alignment::TwoTo<4>> = AlignedVec::new();
+        vec.extend_from_slice(&[1; 32]);
+
+        vec.grow_to_block_multiple(0xFF);
+
+        assert_eq!(&vec[..], [1; 32]);
+    }
+
+    #[test]
+    fn debug_matches_aligned_slice() {
+        let short: AlignedVec<alignment::TwoTo<4>> = (0..8).collect();
+        let long: AlignedVec<alignment::TwoTo<4>> = (0..100).collect();
+
+        assert_eq!(format!("{:?}", &*short), format!("{short:?}"));
+        assert_eq!(format!("{:?}", &*long), format!("{long:?}"));
+        assert_eq!(format!("{:#?}", &*long), format!("{long:#?}"));
+        assert!(format!("{long:?}").contains("… (100 bytes)"));
+    }
+}
@@ -0,0 +1,168 @@
+use super::AlignedVec;
+use crate::alignment::Alignment;
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+/// Draining iterator over a range of an [`AlignedVec`].
+///
+/// Created by [`AlignedVec::drain`]. The drained range is removed from the buffer
+/// when the iterator is dropped, regardless of how many bytes were consumed.
+/// If the iterator is leaked (for example with [`std::mem::forget`]),
+/// the buffer is left truncated at the start of the range.
+pub struct Drain<'a, A: Alignment> {
+    vec: &'a mut AlignedVec<A>,
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<A: Alignment> AlignedVec<A> {
+    /// Remove the bytes in the given `range` from the buffer and return them as an iterator.
+    ///
+    /// The bytes after the range are shifted down when the iterator is dropped,
+    /// so the buffer stays contiguous and its base pointer keeps its alignment.
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end, or the end is greater than `len`.
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len;
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        if start > end {
+            panic!("drain index starts at {start} but ends at {end}");
+        }
+        if end > len {
+            panic!("drain end index {end} out of range for buffer of length {len}");
+        }
+
+        // Set the length to the start of the range first, so that leaking the `Drain`
+        // never exposes the drained bytes.
+        self.len = start;
+
+        Drain {
+            vec: self,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len: len - end,
+        }
+    }
+}
+
+impl<A: Alignment> Iterator for Drain<'_, A> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY:
+        // `front < back <= tail_start`, and all bytes up to `tail_start + tail_len` are initialized.
+        let byte = unsafe { self.vec.bytes_ptr.as_ptr().add(self.front).read() };
+        self.front += 1;
+
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.back - self.front;
+        (size, Some(size))
+    }
+}
+
+impl<A: Alignment> DoubleEndedIterator for Drain<'_, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        // SAFETY:
+        // `front <= back < tail_start`, and all bytes up to `tail_start + tail_len` are initialized.
+        let byte = unsafe { self.vec.bytes_ptr.as_ptr().add(self.back).read() };
+
+        Some(byte)
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for Drain<'_, A> {}
+
+impl<A: Alignment> FusedIterator for Drain<'_, A> {}
+
+impl<A: Alignment> Drop for Drain<'_, A> {
+    #[inline]
+    fn drop(&mut self) {
+        let start = self.vec.len;
+
+        // SAFETY:
+        // Both ranges `tail_start..tail_start + tail_len` and `start..start + tail_len` are within
+        // the allocation, since `start <= tail_start`. `ptr::copy` handles the overlap.
+        unsafe {
+            let ptr = self.vec.bytes_ptr.as_ptr();
+            std::ptr::copy(ptr.add(self.tail_start), ptr.add(start), self.tail_len);
+        }
+        self.vec.len = start + self.tail_len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedVec};
+
+    #[test]
+    fn drain_middle_range() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let drained = vec.drain(2..5).collect::<Vec<_>>();
+
+        assert_eq!(drained, [2, 3, 4]);
+        assert_eq!(&vec[..], [0, 1, 5, 6, 7, 8, 9]);
+        assert_aligned(vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn drain_partially_consumed_removes_whole_range() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let mut drain = vec.drain(..=4);
+
+        assert_eq!(Some(0), drain.next());
+        assert_eq!(Some(4), drain.next_back());
+        drop(drain);
+
+        assert_eq!(&vec[..], [5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn drain_leaked_truncates_at_range_start() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        std::mem::forget(vec.drain(3..));
+
+        assert_eq!(&vec[..], [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "drain end index 11 out of range for buffer of length 10")]
+    fn drain_out_of_bounds() {
+        let mut vec: AlignedVec<alignment::TwoTo<5>> = (0..10).collect();
+        let _ = vec.drain(5..11);
+    }
+}
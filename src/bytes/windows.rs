@@ -0,0 +1,143 @@
+use crate::alignment::Alignment;
+use crate::bytes::{AlignedBytes, AllocationSource};
+use std::ptr::NonNull;
+use windows_sys::Win32::System::Memory::{
+    GetLargePageMinimum, VirtualAlloc, VirtualFree, MEM_COMMIT, MEM_LARGE_PAGES, MEM_RELEASE,
+    MEM_RESERVE, PAGE_READWRITE,
+};
+
+impl<A: Alignment> AlignedBytes<A> {
+    /// Create new block of bytes of given length initialized to all-zeroes,
+    /// backed by large pages if possible.
+    ///
+    /// The memory is allocated with `VirtualAlloc` using `MEM_LARGE_PAGES`, rounding the
+    /// allocation up to a multiple of the large page size. This requires the calling process
+    /// to hold the `SeLockMemoryPrivilege`.
+    ///
+    /// # Fallback
+    /// If large pages are unsupported, the privilege is not held, or [`A::size()`](`Alignment::size`)
+    /// exceeds the large page size, this falls back to [`new_zeroed`](`AlignedBytes::new_zeroed`).
+    /// The alignment guarantee holds in both cases.
+    /// Use [`uses_large_pages`](`AlignedBytes::uses_large_pages`) to check which path was taken.
+    ///
+    /// # Panics
+    /// If allocating memory fails in the fallback path.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+    /// let bytes = AlignedBytes::<alignment::HugePage2MiB>::new_zeroed_large_pages(1024);
+    ///
+    /// assert_eq!(bytes.as_ptr() as usize % alignment::HugePage2MiB::size(), 0);
+    /// assert!(bytes.iter().all(|&x| x == 0));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "windows", windows))))]
+    #[must_use]
+    #[inline]
+    pub fn new_zeroed_large_pages(size: usize) -> Self {
+        Self::try_new_large_pages(size).unwrap_or_else(|| Self::new_zeroed(size))
+    }
+
+    fn try_new_large_pages(size: usize) -> Option<Self> {
+        // SAFETY:
+        // No preconditions.
+        let large_page_size = unsafe { GetLargePageMinimum() };
+
+        if size == 0 || large_page_size == 0 || A::size() > large_page_size {
+            return None;
+        }
+
+        let allocation_size = size.checked_next_multiple_of(large_page_size)?;
+
+        // SAFETY:
+        // Reserving and committing a fresh region has no preconditions. Large page allocations
+        // are aligned to the large page size, which is at least `A::size()`,
+        // and committed memory is zeroed by the OS.
+        let raw_ptr = unsafe {
+            VirtualAlloc(
+                std::ptr::null(),
+                allocation_size,
+                MEM_LARGE_PAGES | MEM_COMMIT | MEM_RESERVE,
+                PAGE_READWRITE,
+            )
+        };
+        // Null signals a failure, most likely due to the privilege not being held.
+        let ptr = NonNull::new(raw_ptr.cast::<u8>())?;
+
+        debug_assert!(
+            (ptr.as_ptr() as usize).is_multiple_of(A::size()),
+            "large page allocation returned by the OS is not aligned"
+        );
+
+        Some(Self::from_allocation(
+            ptr,
+            size,
+            AllocationSource::VirtualAlloc,
+        ))
+    }
+
+    /// Returns whether the bytes are backed by large pages allocated with
+    /// [`new_zeroed_large_pages`](`AlignedBytes::new_zeroed_large_pages`).
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "windows", windows))))]
+    #[must_use]
+    #[inline]
+    pub fn uses_large_pages(&self) -> bool {
+        self.source == AllocationSource::VirtualAlloc
+    }
+
+    // Called on drop for bytes with `AllocationSource::VirtualAlloc`.
+    pub(crate) fn virtual_free(&mut self) {
+        // SAFETY:
+        // The pointer is the base of the region reserved in `try_new_large_pages`,
+        // and with `MEM_RELEASE` the size must be zero to release the entire region.
+        let result = unsafe { VirtualFree(self.bytes_ptr.as_ptr().cast(), 0, MEM_RELEASE) };
+
+        debug_assert_ne!(0, result, "VirtualFree failed to release large pages");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alignment::{self, Alignment};
+    use crate::test::assert_aligned;
+    use crate::AlignedBytes;
+
+    #[test]
+    #[ignore = "requires the SeLockMemoryPrivilege"]
+    fn new_zeroed_large_pages_uses_large_pages() {
+        let bytes =
+            AlignedBytes::<alignment::HugePage2MiB>::new_zeroed_large_pages(3 * 1024 * 1024);
+
+        assert!(bytes.uses_large_pages());
+        assert_aligned(bytes.as_ptr(), alignment::HugePage2MiB::size());
+        assert!(bytes.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn new_zeroed_large_pages_falls_back_when_alignment_exceeds_large_page() {
+        // Large pages are 2 MiB on both x86-64 and ARM64 Windows.
+        let bytes = AlignedBytes::<alignment::TwoTo<22>>::new_zeroed_large_pages(1024);
+
+        assert!(!bytes.uses_large_pages());
+        assert_aligned(bytes.as_ptr(), alignment::TwoTo::<22>::size());
+        assert!(bytes.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn new_zeroed_large_pages_is_aligned_and_zeroed() {
+        let mut bytes = AlignedBytes::<alignment::Page>::new_zeroed_large_pages(1024);
+
+        assert_aligned(bytes.as_ptr(), alignment::Page::size());
+        assert!(bytes.iter().all(|&x| x == 0));
+        bytes[1023] = 42;
+        assert_eq!(42, bytes[1023]);
+    }
+
+    #[test]
+    fn empty_large_page_bytes_do_not_use_large_pages() {
+        let bytes = AlignedBytes::<alignment::HugePage2MiB>::new_zeroed_large_pages(0);
+
+        assert!(bytes.is_empty());
+        assert!(!bytes.uses_large_pages());
+    }
+}
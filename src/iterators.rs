@@ -1,8 +1,21 @@
 use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
 use crate::slice::AlignedSlice;
 use std::iter::FusedIterator;
 use std::mem;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
+
+mod cmp;
+
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use par::*;
+
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+mod simd;
 
 /// Thin wrapper that represents an [`AlignedSlice`] of size at most the alignment size.
 ///
@@ -20,6 +33,41 @@ pub struct AlignedBlockIterator<'a, A: Alignment> {
     bytes: &'a AlignedSlice<A>,
 }
 
+/// Iterator over mutable [`AlignedBlocks`](`AlignedBlock`) of a given aligned bytes span.
+pub struct AlignedBlockIteratorMut<'a, A: Alignment> {
+    bytes: &'a mut AlignedSlice<A>,
+}
+
+/// Iterator over full [`AlignedBlocks`](`AlignedBlock`) of a given aligned bytes span.
+///
+/// Every block yielded is of size exactly [`A::size()`](`Alignment::size`). The trailing
+/// bytes that do not form a full block are skipped, and can be obtained with
+/// [`remainder`](`AlignedBlockExactIterator::remainder`).
+pub struct AlignedBlockExactIterator<'a, A: Alignment> {
+    blocks: AlignedBlockIterator<'a, A>,
+    remainder: &'a AlignedBlock<A>,
+}
+
+/// Iterator over overlapping windows of consecutive [`AlignedBlocks`](`AlignedBlock`)
+/// of a given aligned bytes span.
+///
+/// Each window spans the same number of full blocks and consecutive windows
+/// are one block apart, so every window is aligned.
+pub struct AlignedBlockWindows<'a, A: Alignment> {
+    bytes: &'a AlignedSlice<A>,
+    window_len: usize,
+}
+
+/// Iterator over non-overlapping chunks of consecutive [`AlignedBlocks`](`AlignedBlock`)
+/// of a given aligned bytes span.
+///
+/// Each chunk spans the same number of blocks, except possibly the last one, which can be shorter.
+/// Since every chunk starts at a multiple of [`A::size()`](`Alignment::size`), all chunks are aligned.
+pub struct AlignedChunks<'a, A: Alignment> {
+    bytes: &'a AlignedSlice<A>,
+    chunk_len: usize,
+}
+
 impl<'a, A: Alignment> AlignedBlockIterator<'a, A> {
     #[must_use]
     #[inline]
@@ -27,8 +75,22 @@ impl<'a, A: Alignment> AlignedBlockIterator<'a, A> {
         Self { bytes: slice }
     }
 
+    /// Return the bytes that have not yet been yielded by the iterator.
+    ///
+    /// The remaining bytes always start at an [`A::size()`](`Alignment::size`) boundary.
+    /// They can span multiple blocks, so this is an [`AlignedSlice`] and not an [`AlignedBlock`].
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &'a AlignedSlice<A> {
+        self.bytes
+    }
+
     /// Skip ahead by `offset` blocks. This is equivalent to
     /// skipping `offset * A::size()` bytes of the underlying data.
+    ///
+    /// # Panics
+    /// If `offset` is negative, or there are less than `offset` blocks remaining.
+    /// See [`AlignedSlice::offset`].
     #[inline]
     pub fn offset(&mut self, offset: isize) {
         self.bytes = self.bytes.offset(offset);
@@ -47,6 +109,31 @@ impl<A: Alignment> Deref for AlignedBlock<A> {
     }
 }
 
+impl<A: Alignment> DerefMut for AlignedBlock<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // repr(transparent) and the requirements for AlignedSlice are
+        // a subset of those of AlignedBlock. The length of the slice
+        // cannot be changed through the mutable reference.
+        unsafe { mem::transmute(self) }
+    }
+}
+
+impl<A: Alignment> std::fmt::Debug for AlignedBlock<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.slice, f)
+    }
+}
+
+impl<A: Alignment> std::hash::Hash for AlignedBlock<A> {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.slice, state)
+    }
+}
+
 impl<A: Alignment> AlignedBlock<A> {
     /// Returns the length of the block. Guaranteed to be at most [`A::size()`](`Alignment::size`).
     #[must_use]
@@ -62,6 +149,99 @@ impl<A: Alignment> AlignedBlock<A> {
         self.slice.is_empty()
     }
 
+    /// View a full block as a fixed-size array.
+    ///
+    /// Returns `Some` only if `N` is equal to [`A::size()`](`Alignment::size`) and the block is full,
+    /// i.e. it is not the short trailing block of a slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    /// let mut blocks = bytes.iter_blocks();
+    ///
+    /// assert_eq!(blocks.next().unwrap().as_array::<4>(), Some(&[1, 2, 3, 4]));
+    /// assert_eq!(blocks.next().unwrap().as_array::<4>(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_array<const N: usize>(&self) -> Option<&[u8; N]> {
+        if N != A::size() {
+            return None;
+        }
+
+        let slice: &[u8] = &self.slice;
+        slice.try_into().ok()
+    }
+
+    /// Copy the block into new owned [`AlignedBytes`] of the same length and alignment.
+    ///
+    /// The block may be shorter than [`A::size()`](`Alignment::size`) if it is the trailing
+    /// block of a slice, in which case so are the returned bytes.
+    ///
+    /// ## Note
+    /// [`ToOwned`] is not implemented for blocks, since it would require
+    /// [`AlignedBytes<A>`] to be borrowable as an [`AlignedBlock<A>`], which is only valid
+    /// if it is not longer than [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    /// let last = bytes.iter_blocks().next_back().unwrap().to_aligned_bytes();
+    ///
+    /// assert_eq!(last, [5, 6]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_aligned_bytes(&self) -> AlignedBytes<A> {
+        self.slice.to_owned()
+    }
+
+    /// Relax the alignment of the block to a smaller one.
+    ///
+    /// A block of `B` can be at most [`B::size()`](`Alignment::size`) long,
+    /// so the block must also fit within that size. To relax the alignment of a longer block,
+    /// relax the underlying [`AlignedSlice`] with [`AlignedSlice::relax_alignment`] instead.
+    ///
+    /// # Panics
+    /// If `B::size()` > `A::size()`, or the length of the block exceeds `B::size()`.
+    #[must_use]
+    #[inline]
+    pub fn relax_alignment<B: Alignment>(&self) -> &AlignedBlock<B> {
+        if A::size() < B::size() {
+            panic!("target alignment is larger than source alignment, the 'relax_alignment' conversion is not valid")
+        }
+        if self.len() > B::size() {
+            panic!(
+                "block of length {} does not fit in a block of the target alignment of size {}",
+                self.len(),
+                B::size()
+            )
+        }
+
+        // SAFETY:
+        // The alignment is retained as in `AlignedSlice::relax_alignment`,
+        // and the length is asserted above to not exceed `B::size()`.
+        unsafe { mem::transmute::<&Self, &AlignedBlock<B>>(self) }
+    }
+
+    /// Relax the alignment of the block to a smaller one, or return `None` if that is not possible.
+    ///
+    /// This is the non-panicking version of [`relax_alignment`](`AlignedBlock::relax_alignment`).
+    /// It returns `None` if `B::size()` > `A::size()`, or the length of the block exceeds `B::size()`.
+    #[must_use]
+    #[inline]
+    pub fn try_relax_alignment<B: Alignment>(&self) -> Option<&AlignedBlock<B>> {
+        if A::size() < B::size() || self.len() > B::size() {
+            return None;
+        }
+
+        // SAFETY:
+        // Same as in `relax_alignment`, the conditions are checked above.
+        Some(unsafe { mem::transmute::<&Self, &AlignedBlock<B>>(self) })
+    }
+
     /// Return the size of the alignment in bytes. Equal to [`A::size()`](`Alignment::size`).
     ///
     /// ## Note
@@ -87,14 +267,14 @@ impl<'a, A: Alignment> Iterator for AlignedBlockIterator<'a, A> {
         if self.bytes.len() < A::size() {
             // SAFETY:
             // `self.bytes` is aligned to `A` and we checked its size does not exceed `A::size()`.
-            let chunk = unsafe { mem::transmute(self.bytes) };
+            let chunk = unsafe { mem::transmute::<&AlignedSlice<A>, &AlignedBlock<A>>(self.bytes) };
             self.bytes = Default::default();
             return Some(chunk);
         }
 
         // SAFETY:
         // `self.bytes` is aligned to `A` and we take exactly one block of size `A::size()`.
-        let chunk = unsafe { mem::transmute(&self.bytes[..A::size()]) };
+        let chunk = unsafe { mem::transmute::<&[u8], &AlignedBlock<A>>(&self.bytes[..A::size()]) };
         self.bytes = self.bytes.offset(1);
 
         Some(chunk)
@@ -102,18 +282,299 @@ impl<'a, A: Alignment> Iterator for AlignedBlockIterator<'a, A> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = (self.bytes.len() + A::size() - 1) / A::size();
+        let size = self.bytes.len().div_ceil(A::size());
         (size, Some(size))
     }
+
+    // Skipping blocks is just an offset into the underlying slice, no need to walk them one by one.
+    // `advance_by` would benefit from the same, but it cannot be overridden on stable.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.bytes = Default::default();
+            return None;
+        }
+
+        // The cast is lossless, since `n` is less than the number of blocks, which is at most `isize::MAX`.
+        self.offset(n as isize);
+        self.next()
+    }
+}
+
+impl<A: Alignment> DoubleEndedIterator for AlignedBlockIterator<'_, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let len = self.bytes.len();
+        let last_block_len = match len % A::size() {
+            0 => A::size(),
+            remainder => remainder,
+        };
+        let last_block_start = len - last_block_len;
+
+        // SAFETY:
+        // `last_block_start` is a multiple of `A::size()`, so the block is aligned to `A`,
+        // and its size does not exceed `A::size()`.
+        let chunk =
+            unsafe { mem::transmute::<&[u8], &AlignedBlock<A>>(&self.bytes[last_block_start..]) };
+        // SAFETY:
+        // The remaining bytes start at the same, aligned, address.
+        self.bytes =
+            unsafe { mem::transmute::<&[u8], &AlignedSlice<A>>(&self.bytes[..last_block_start]) };
+
+        Some(chunk)
+    }
 }
 
 impl<A: Alignment> ExactSizeIterator for AlignedBlockIterator<'_, A> {}
 
 impl<A: Alignment> FusedIterator for AlignedBlockIterator<'_, A> {}
 
+impl<'a, A: Alignment> AlignedBlockIteratorMut<'a, A> {
+    #[must_use]
+    #[inline]
+    pub(crate) fn new(slice: &'a mut AlignedSlice<A>) -> Self {
+        Self { bytes: slice }
+    }
+}
+
+impl<'a, A: Alignment> Iterator for AlignedBlockIteratorMut<'a, A> {
+    type Item = &'a mut AlignedBlock<A>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let aligned: &'a mut AlignedSlice<A> = mem::take(&mut self.bytes);
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        let bytes = unsafe { mem::transmute::<&'a mut AlignedSlice<A>, &'a mut [u8]>(aligned) };
+        let block_len = std::cmp::min(A::size(), bytes.len());
+        let (chunk, rest) = bytes.split_at_mut(block_len);
+
+        // SAFETY:
+        // The chunk starts where `self.bytes` did, so it is aligned to `A`, and its size does not exceed `A::size()`.
+        // The rest starts at `A::size()` if it is non-empty, so it is also aligned.
+        unsafe {
+            self.bytes = mem::transmute::<&'a mut [u8], &'a mut AlignedSlice<A>>(rest);
+            Some(mem::transmute::<&'a mut [u8], &'a mut AlignedBlock<A>>(
+                chunk,
+            ))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.bytes.len().div_ceil(A::size());
+        (size, Some(size))
+    }
+}
+
+impl<'a, A: Alignment> DoubleEndedIterator for AlignedBlockIteratorMut<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let aligned: &'a mut AlignedSlice<A> = mem::take(&mut self.bytes);
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        let bytes = unsafe { mem::transmute::<&'a mut AlignedSlice<A>, &'a mut [u8]>(aligned) };
+        let last_block_len = match bytes.len() % A::size() {
+            0 => A::size(),
+            remainder => remainder,
+        };
+        let last_block_start = bytes.len() - last_block_len;
+        let (rest, chunk) = bytes.split_at_mut(last_block_start);
+
+        // SAFETY:
+        // `last_block_start` is a multiple of `A::size()`, so the block is aligned to `A`,
+        // and its size does not exceed `A::size()`. The rest starts at the same, aligned, address.
+        unsafe {
+            self.bytes = mem::transmute::<&'a mut [u8], &'a mut AlignedSlice<A>>(rest);
+            Some(mem::transmute::<&'a mut [u8], &'a mut AlignedBlock<A>>(
+                chunk,
+            ))
+        }
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for AlignedBlockIteratorMut<'_, A> {}
+
+impl<A: Alignment> FusedIterator for AlignedBlockIteratorMut<'_, A> {}
+
+impl<'a, A: Alignment> AlignedBlockExactIterator<'a, A> {
+    #[must_use]
+    #[inline]
+    pub(crate) fn new(slice: &'a AlignedSlice<A>) -> Self {
+        let full_len = slice.len() - slice.len() % A::size();
+
+        // SAFETY:
+        // Both parts are aligned, since `full_len` is a multiple of `A::size()`,
+        // and the remainder has size less than `A::size()`.
+        let (full, remainder) = unsafe {
+            (
+                mem::transmute::<&[u8], &AlignedSlice<A>>(&slice[..full_len]),
+                mem::transmute::<&[u8], &AlignedBlock<A>>(&slice[full_len..]),
+            )
+        };
+
+        Self {
+            blocks: AlignedBlockIterator::new(full),
+            remainder,
+        }
+    }
+
+    /// Return the trailing bytes that do not form a full block.
+    /// They are never yielded by the iterator.
+    ///
+    /// The remainder starts at an [`A::size()`](`Alignment::size`) boundary,
+    /// so it is aligned, but its length is less than [`A::size()`](`Alignment::size`).
+    #[must_use]
+    #[inline]
+    pub fn remainder(&self) -> &'a AlignedBlock<A> {
+        self.remainder
+    }
+}
+
+impl<'a, A: Alignment> Iterator for AlignedBlockExactIterator<'a, A> {
+    type Item = &'a AlignedBlock<A>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.blocks.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.blocks.size_hint()
+    }
+}
+
+impl<A: Alignment> DoubleEndedIterator for AlignedBlockExactIterator<'_, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.blocks.next_back()
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for AlignedBlockExactIterator<'_, A> {}
+
+impl<A: Alignment> FusedIterator for AlignedBlockExactIterator<'_, A> {}
+
+impl<'a, A: Alignment> AlignedBlockWindows<'a, A> {
+    #[must_use]
+    #[inline]
+    pub(crate) fn new(slice: &'a AlignedSlice<A>, blocks: usize) -> Self {
+        if blocks == 0 {
+            panic!("window size must be non-zero");
+        }
+
+        let window_len = blocks
+            .checked_mul(A::size())
+            .expect("window size in bytes overflows usize");
+
+        Self {
+            bytes: slice,
+            window_len,
+        }
+    }
+}
+
+impl<'a, A: Alignment> Iterator for AlignedBlockWindows<'a, A> {
+    type Item = &'a AlignedSlice<A>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.len() < self.window_len {
+            return None;
+        }
+
+        // SAFETY:
+        // `self.bytes` is aligned to `A` and the window starts at its beginning.
+        let window =
+            unsafe { mem::transmute::<&[u8], &AlignedSlice<A>>(&self.bytes[..self.window_len]) };
+        self.bytes = self.bytes.offset(1);
+
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = if self.bytes.len() < self.window_len {
+            0
+        } else {
+            (self.bytes.len() - self.window_len) / A::size() + 1
+        };
+        (size, Some(size))
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for AlignedBlockWindows<'_, A> {}
+
+impl<A: Alignment> FusedIterator for AlignedBlockWindows<'_, A> {}
+
+impl<'a, A: Alignment> AlignedChunks<'a, A> {
+    #[must_use]
+    #[inline]
+    pub(crate) fn new(slice: &'a AlignedSlice<A>, blocks: usize) -> Self {
+        if blocks == 0 {
+            panic!("chunk size must be non-zero");
+        }
+
+        let chunk_len = blocks
+            .checked_mul(A::size())
+            .expect("chunk size in bytes overflows usize");
+
+        Self {
+            bytes: slice,
+            chunk_len,
+        }
+    }
+}
+
+impl<'a, A: Alignment> Iterator for AlignedChunks<'a, A> {
+    type Item = &'a AlignedSlice<A>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let len = std::cmp::min(self.chunk_len, self.bytes.len());
+        let (chunk, rest) = self.bytes.split_at(len);
+
+        // SAFETY:
+        // `self.bytes` is aligned to `A`, the chunk starts at its beginning,
+        // and the rest starts at `len`, which is either a multiple of `A::size()` or the end of the slice.
+        unsafe {
+            self.bytes = mem::transmute::<&[u8], &AlignedSlice<A>>(rest);
+            Some(mem::transmute::<&[u8], &AlignedSlice<A>>(chunk))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.bytes.len().div_ceil(self.chunk_len);
+        (size, Some(size))
+    }
+}
+
+impl<A: Alignment> ExactSizeIterator for AlignedChunks<'_, A> {}
+
+impl<A: Alignment> FusedIterator for AlignedChunks<'_, A> {}
+
 #[cfg(test)]
 mod tests {
-    use crate::{alignment, AlignedBytes};
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBlock, AlignedBytes};
 
     #[test]
     fn alignment_size_equal_to_alignment_type() {
@@ -123,4 +584,330 @@ mod tests {
 
         assert_eq!(128, block.alignment_size());
     }
+
+    #[test]
+    fn to_aligned_bytes_of_full_block() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+        let block = bytes.iter_blocks().nth(1).unwrap();
+
+        let owned = block.to_aligned_bytes();
+        drop(bytes);
+
+        assert_eq!(owned, (16..32).collect::<Vec<u8>>());
+        assert_aligned(owned.as_ptr(), 16);
+    }
+
+    #[test]
+    fn to_aligned_bytes_of_short_block() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+        let block = bytes.iter_blocks().next_back().unwrap();
+
+        let owned = block.to_aligned_bytes();
+
+        assert_eq!(8, owned.len());
+        assert_eq!(owned, (32..40).collect::<Vec<u8>>());
+        assert_aligned(owned.as_ptr(), 16);
+    }
+
+    #[test]
+    fn chunks_aligned_yields_aligned_chunks_of_whole_blocks() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..100).collect();
+
+        let chunks = bytes.chunks_aligned(2);
+        assert_eq!(4, chunks.len());
+
+        let chunks = chunks.collect::<Vec<_>>();
+        assert_eq!(**chunks[0], (0..32).collect::<Vec<u8>>());
+        assert_eq!(**chunks[1], (32..64).collect::<Vec<u8>>());
+        assert_eq!(**chunks[2], (64..96).collect::<Vec<u8>>());
+        assert_eq!(**chunks[3], (96..100).collect::<Vec<u8>>());
+
+        for chunk in chunks {
+            assert_aligned(chunk.as_ptr(), 16);
+        }
+    }
+
+    #[test]
+    fn chunks_aligned_over_exact_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..64).collect();
+
+        let lengths = bytes.chunks_aligned(2).map(|c| c.len()).collect::<Vec<_>>();
+
+        assert_eq!(lengths, [32, 32]);
+    }
+
+    #[test]
+    fn chunks_aligned_over_empty_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::default();
+
+        assert_eq!(0, bytes.chunks_aligned(1).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_aligned_of_zero_blocks_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..64).collect();
+
+        let _ = bytes.chunks_aligned(0);
+    }
+
+    #[test]
+    fn as_array_of_full_block() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..40).collect();
+        let block = bytes.iter_blocks().next().unwrap();
+
+        let array: &[u8; 32] = block.as_array().unwrap();
+
+        assert_eq!(array.as_slice(), (0..32).collect::<Vec<u8>>());
+        assert_aligned(array.as_ptr(), 32);
+    }
+
+    #[test]
+    fn as_array_of_short_block_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..40).collect();
+        let block = bytes.iter_blocks().next_back().unwrap();
+
+        assert!(block.as_array::<32>().is_none());
+        assert!(block.as_array::<8>().is_none());
+    }
+
+    #[test]
+    fn as_array_with_size_different_from_alignment_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..64).collect();
+        let block = bytes.iter_blocks().next().unwrap();
+
+        assert!(block.as_array::<16>().is_none());
+        assert!(block.as_array::<64>().is_none());
+    }
+
+    #[test]
+    fn relax_alignment_of_block_that_fits() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(20);
+        let block = bytes.iter_blocks().nth(1).unwrap();
+
+        let relaxed: &AlignedBlock<alignment::Four> = block.relax_alignment();
+
+        assert_eq!(relaxed.len(), 4);
+        assert_eq!(relaxed.as_ptr(), block.as_ptr());
+        assert_eq!(4, relaxed.alignment_size());
+    }
+
+    #[test]
+    #[should_panic(expected = "target alignment is larger than source alignment")]
+    fn relax_alignment_of_block_to_larger_alignment_panics() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(4);
+        let block = bytes.iter_blocks().next().unwrap();
+
+        let _: &AlignedBlock<alignment::Eight> = block.relax_alignment();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "block of length 16 does not fit in a block of the target alignment of size 4"
+    )]
+    fn relax_alignment_of_block_too_long_for_target_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(16);
+        let block = bytes.iter_blocks().next().unwrap();
+
+        let _: &AlignedBlock<alignment::Four> = block.relax_alignment();
+    }
+
+    #[test]
+    fn try_relax_alignment_of_block() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(10);
+        let mut blocks = bytes.iter_blocks();
+        let full = blocks.next().unwrap();
+        let short = blocks.next().unwrap();
+
+        assert!(full.try_relax_alignment::<alignment::Eight>().is_some());
+        assert!(full.try_relax_alignment::<alignment::Four>().is_none());
+        assert!(full.try_relax_alignment::<alignment::TwoTo<4>>().is_none());
+        assert_eq!(
+            short
+                .try_relax_alignment::<alignment::Two>()
+                .map(|b| b.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot offset backwards")]
+    fn offset_iterator_backwards_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let mut iter = bytes.iter_blocks();
+        iter.next();
+
+        iter.offset(-1);
+    }
+
+    #[test]
+    fn iterate_backwards_over_exact_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+        let blocks = bytes
+            .iter_blocks()
+            .rev()
+            .map(|b| b.to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(blocks, [vec![5, 6], vec![3, 4], vec![1, 2]]);
+    }
+
+    #[test]
+    fn iterate_backwards_returns_remainder_first() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let blocks = bytes
+            .iter_blocks()
+            .rev()
+            .map(|b| b.to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(blocks, [vec![5], vec![3, 4], vec![1, 2]]);
+    }
+
+    #[test]
+    fn iterate_from_both_ends_meets_in_the_middle() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let mut iter = bytes.iter_blocks();
+
+        assert_eq!(iter.next().unwrap().to_vec(), [1, 2]);
+        assert_eq!(iter.next_back().unwrap().to_vec(), [5]);
+        assert_eq!(1, iter.len());
+        assert_eq!(iter.next_back().unwrap().to_vec(), [3, 4]);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn size_hint_is_ceiling_of_block_count() {
+        for (len, expected) in [(0, 0), (1, 1), (15, 1), (16, 1), (17, 2), (48, 3), (49, 4)] {
+            let mut bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(len);
+
+            assert_eq!(bytes.iter_blocks().size_hint(), (expected, Some(expected)));
+            assert_eq!(
+                bytes.iter_blocks_mut().size_hint(),
+                (expected, Some(expected))
+            );
+            assert_eq!(bytes.iter_blocks().count(), expected);
+        }
+    }
+
+    #[test]
+    fn iterate_exact_over_exact_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4]);
+        let iter = bytes.iter_blocks_exact();
+
+        assert_eq!(2, iter.len());
+        assert!(iter.remainder().is_empty());
+        assert_eq!(
+            iter.map(|b| b.to_vec()).collect::<Vec<_>>(),
+            [vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn iterate_exact_skips_remainder() {
+        let bytes: AlignedBytes<alignment::TwoTo<2>> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+        let iter = bytes.iter_blocks_exact();
+
+        assert_eq!(1, iter.len());
+        assert_eq!(iter.remainder().to_vec(), [5, 6]);
+        assert_aligned(iter.remainder().as_ptr(), 4);
+        assert_eq!(
+            iter.map(|b| b.to_vec()).collect::<Vec<_>>(),
+            [vec![1, 2, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn as_slice_returns_unconsumed_bytes() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let mut iter = bytes.iter_blocks();
+        iter.next();
+        iter.next_back();
+
+        assert_eq!(iter.as_slice(), &[3, 4]);
+        assert_aligned(iter.as_slice().as_ptr(), 2);
+
+        iter.next();
+
+        assert!(iter.as_slice().is_empty());
+    }
+
+    #[test]
+    fn windows_of_two_blocks() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5, 6, 7, 8]);
+        let windows = bytes.windows_blocks(2);
+
+        assert_eq!(3, windows.len());
+
+        let windows = windows.collect::<Vec<_>>();
+
+        assert_eq!(windows[0], &[1, 2, 3, 4]);
+        assert_eq!(windows[1], &[3, 4, 5, 6]);
+        assert_eq!(windows[2], &[5, 6, 7, 8]);
+
+        for window in windows {
+            assert_aligned(window.as_ptr(), 2);
+        }
+    }
+
+    #[test]
+    fn windows_larger_than_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3]);
+
+        assert_eq!(0, bytes.windows_blocks(2).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_of_zero_blocks() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3]);
+        let _ = bytes.windows_blocks(0);
+    }
+
+    #[test]
+    fn iterate_mut_over_blocks() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+
+        for (i, block) in bytes.iter_blocks_mut().enumerate() {
+            assert_aligned(block.as_ptr(), 2);
+            block.fill(i as u8);
+        }
+
+        assert_eq!(bytes, [0, 0, 1, 1, 2]);
+    }
+
+    #[test]
+    fn iterate_mut_backwards_returns_remainder_first() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3, 4, 5]);
+        let blocks = bytes
+            .iter_blocks_mut()
+            .rev()
+            .map(|b| b.to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(blocks, [vec![5], vec![3, 4], vec![1, 2]]);
+    }
+
+    #[test]
+    fn nth_skips_directly_to_block() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> =
+            AlignedBytes::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let mut iter = bytes.iter_blocks();
+
+        assert_eq!(iter.nth(3).unwrap().to_vec(), [7, 8]);
+        assert_eq!(1, iter.len());
+        assert_eq!(iter.next().unwrap().to_vec(), [9, 10]);
+    }
+
+    #[test]
+    fn nth_past_the_end_exhausts_iterator() {
+        let bytes: AlignedBytes<alignment::TwoTo<1>> = AlignedBytes::from([1, 2, 3]);
+        let mut iter = bytes.iter_blocks();
+
+        assert!(iter.nth(1).is_some());
+        assert!(iter.nth(1).is_none());
+        assert_eq!(0, iter.len());
+        assert!(iter.next().is_none());
+    }
 }
@@ -0,0 +1,123 @@
+use super::AlignedBlock;
+use crate::alignment::Alignment;
+use crate::slice::AlignedSlice;
+
+impl<A: Alignment> PartialEq for AlignedBlock<A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.slice.eq(&other.slice)
+    }
+}
+
+impl<A: Alignment> Eq for AlignedBlock<A> {}
+
+impl<A: Alignment> PartialEq<AlignedSlice<A>> for AlignedBlock<A> {
+    #[inline]
+    fn eq(&self, other: &AlignedSlice<A>) -> bool {
+        self.slice.eq(other)
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedBlock<A>> for AlignedSlice<A> {
+    #[inline]
+    fn eq(&self, other: &AlignedBlock<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<A: Alignment> PartialEq<[u8]> for AlignedBlock<A> {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.slice.eq(other)
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedBlock<A>> for [u8] {
+    #[inline]
+    fn eq(&self, other: &AlignedBlock<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<A: Alignment> PartialEq<Vec<u8>> for AlignedBlock<A> {
+    #[inline]
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        let other_slice: &[u8] = other;
+        self.eq(other_slice)
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedBlock<A>> for Vec<u8> {
+    #[inline]
+    fn eq(&self, other: &AlignedBlock<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<A: Alignment, const N: usize> PartialEq<[u8; N]> for AlignedBlock<A> {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.slice.eq(other)
+    }
+}
+
+impl<A: Alignment, const N: usize> PartialEq<AlignedBlock<A>> for [u8; N] {
+    #[inline]
+    fn eq(&self, other: &AlignedBlock<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes, AlignedSlice};
+
+    #[test]
+    fn blocks_are_equal_to_array_literals() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+        let mut blocks = bytes.iter_blocks();
+
+        assert_eq!(blocks.next().unwrap(), &[1, 2, 3, 4]);
+        assert_eq!(&[5, 6], blocks.next().unwrap());
+        assert!(blocks.next().is_none());
+    }
+
+    #[test]
+    fn block_is_not_equal_to_array_with_different_contents() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4]);
+        let block = bytes.iter_blocks().next().unwrap();
+
+        assert_ne!(block, &[1, 2, 3, 5]);
+        assert_ne!(block, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn block_is_equal_to_slice_with_same_contents() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4]);
+        let block = bytes.iter_blocks().next().unwrap();
+        let slice: &AlignedSlice<alignment::Four> = &bytes;
+
+        assert_eq!(block, slice);
+        assert_eq!(slice, block);
+    }
+
+    #[test]
+    fn block_is_equal_to_byte_slice_and_vec() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4]);
+        let block = bytes.iter_blocks().next().unwrap();
+        let vec = vec![1, 2, 3, 4];
+
+        assert_eq!(block, &vec[..]);
+        assert_eq!(&vec[..], block);
+        assert_eq!(*block, vec);
+        assert_eq!(vec, *block);
+    }
+
+    #[test]
+    fn blocks_from_different_buffers_are_equal() {
+        let bytes1: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4]);
+        let bytes2: AlignedBytes<alignment::Four> = AlignedBytes::from([0, 0, 0, 0, 1, 2, 3, 4]);
+
+        assert_eq!(bytes1.iter_blocks().next(), bytes2.iter_blocks().nth(1));
+    }
+}
@@ -0,0 +1,241 @@
+use super::{AlignedBlock, AlignedBlockIterator, AlignedBlockIteratorMut};
+use crate::alignment::Alignment;
+use crate::slice::AlignedSlice;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use std::mem;
+
+/// Parallel iterator over [`AlignedBlocks`](`AlignedBlock`) of a given aligned bytes span.
+///
+/// The work is split only at block boundaries, so every block handed to a thread is aligned.
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub struct ParAlignedBlockIterator<'a, A: Alignment> {
+    bytes: &'a AlignedSlice<A>,
+}
+
+/// Parallel iterator over mutable [`AlignedBlocks`](`AlignedBlock`) of a given aligned bytes span.
+///
+/// The work is split only at block boundaries, so every block handed to a thread is aligned.
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub struct ParAlignedBlockIteratorMut<'a, A: Alignment> {
+    bytes: &'a mut AlignedSlice<A>,
+}
+
+impl<A: Alignment> AlignedSlice<A> {
+    /// Return a parallel iterator over consecutive aligned blocks of the slice.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[must_use]
+    #[inline]
+    pub fn par_iter_blocks(&self) -> ParAlignedBlockIterator<'_, A> {
+        ParAlignedBlockIterator { bytes: self }
+    }
+
+    /// Return a parallel iterator over consecutive mutable aligned blocks of the slice.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[must_use]
+    #[inline]
+    pub fn par_iter_blocks_mut(&mut self) -> ParAlignedBlockIteratorMut<'_, A> {
+        ParAlignedBlockIteratorMut { bytes: self }
+    }
+}
+
+// Number of bytes before the split point after `index` blocks.
+fn split_point<A: Alignment>(len: usize, index: usize) -> usize {
+    std::cmp::min(index.saturating_mul(A::size()), len)
+}
+
+fn split_aligned<A: Alignment>(
+    bytes: &AlignedSlice<A>,
+    index: usize,
+) -> (&AlignedSlice<A>, &AlignedSlice<A>) {
+    let mid = split_point::<A>(bytes.len(), index);
+    let (left, right) = bytes.split_at(mid);
+
+    // SAFETY:
+    // Both parts are aligned, since `mid` is either a multiple of `A::size()`
+    // or the end of the slice, and the right part is empty.
+    unsafe {
+        (
+            mem::transmute::<&[u8], &AlignedSlice<A>>(left),
+            mem::transmute::<&[u8], &AlignedSlice<A>>(right),
+        )
+    }
+}
+
+fn split_aligned_mut<A: Alignment>(
+    bytes: &mut AlignedSlice<A>,
+    index: usize,
+) -> (&mut AlignedSlice<A>, &mut AlignedSlice<A>) {
+    let mid = split_point::<A>(bytes.len(), index);
+    let (left, right) = bytes.split_at_mut(mid);
+
+    // SAFETY:
+    // Both parts are aligned, since `mid` is either a multiple of `A::size()`
+    // or the end of the slice, and the right part is empty.
+    unsafe {
+        (
+            mem::transmute::<&mut [u8], &mut AlignedSlice<A>>(left),
+            mem::transmute::<&mut [u8], &mut AlignedSlice<A>>(right),
+        )
+    }
+}
+
+struct BlockProducer<'a, A: Alignment> {
+    bytes: &'a AlignedSlice<A>,
+}
+
+struct BlockProducerMut<'a, A: Alignment> {
+    bytes: &'a mut AlignedSlice<A>,
+}
+
+impl<'a, A: Alignment + Sync> Producer for BlockProducer<'a, A> {
+    type Item = &'a AlignedBlock<A>;
+    type IntoIter = AlignedBlockIterator<'a, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        AlignedBlockIterator::new(self.bytes)
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = split_aligned(self.bytes, index);
+        (Self { bytes: left }, Self { bytes: right })
+    }
+}
+
+impl<'a, A: Alignment + Send> Producer for BlockProducerMut<'a, A> {
+    type Item = &'a mut AlignedBlock<A>;
+    type IntoIter = AlignedBlockIteratorMut<'a, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        AlignedBlockIteratorMut::new(self.bytes)
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = split_aligned_mut(self.bytes, index);
+        (Self { bytes: left }, Self { bytes: right })
+    }
+}
+
+impl<'a, A: Alignment + Sync> ParallelIterator for ParAlignedBlockIterator<'a, A> {
+    type Item = &'a AlignedBlock<A>;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<A: Alignment + Sync> IndexedParallelIterator for ParAlignedBlockIterator<'_, A> {
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len().div_ceil(A::size())
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(BlockProducer { bytes: self.bytes })
+    }
+}
+
+impl<'a, A: Alignment + Send> ParallelIterator for ParAlignedBlockIteratorMut<'a, A> {
+    type Item = &'a mut AlignedBlock<A>;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<A: Alignment + Send> IndexedParallelIterator for ParAlignedBlockIteratorMut<'_, A> {
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes.len().div_ceil(A::size())
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(BlockProducerMut { bytes: self.bytes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes};
+    use rayon::prelude::*;
+
+    #[test]
+    fn parallel_sum_equals_sequential_sum() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> =
+            AlignedBytes::new_initialize(1000, |i| (i % 256) as u8);
+        let sequential: u64 = bytes.iter().map(|&b| b as u64).sum();
+        let parallel: u64 = bytes
+            .par_iter_blocks()
+            .map(|block| {
+                assert_aligned(block.as_ptr(), 16);
+                block.iter().map(|&b| b as u64).sum::<u64>()
+            })
+            .sum();
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(63, bytes.par_iter_blocks().len());
+    }
+
+    #[test]
+    fn parallel_mut_writes_every_block() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(1000);
+        bytes
+            .par_iter_blocks_mut()
+            .enumerate()
+            .for_each(|(i, block)| {
+                assert_aligned(block.as_ptr(), 16);
+                block.fill(i as u8);
+            });
+
+        for (i, block) in bytes.iter_blocks().enumerate() {
+            assert!(block.iter().all(|&b| b == i as u8));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::AlignedBlock;
+use crate::alignment::{Alignment, SimdBlock};
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(target_arch = "x86_64")] {
+        #[cfg(target_feature = "sse2")]
+        use std::arch::x86_64::{__m128i, _mm_load_si128, _mm_store_si128};
+        #[cfg(target_feature = "avx")]
+        use std::arch::x86_64::{__m256i, _mm256_load_si256, _mm256_store_si256};
+    }
+    else {
+        #[cfg(target_feature = "sse2")]
+        use std::arch::x86::{__m128i, _mm_load_si128, _mm_store_si128};
+        #[cfg(target_feature = "avx")]
+        use std::arch::x86::{__m256i, _mm256_load_si256, _mm256_store_si256};
+    }
+}
+
+impl AlignedBlock<SimdBlock> {
+    // Panics if the block is shorter than the full alignment, i.e. it is the trailing block of a slice.
+    #[inline(always)]
+    fn assert_full(&self) {
+        assert_eq!(
+            self.len(),
+            SimdBlock::size(),
+            "SIMD loads and stores require a full block"
+        );
+    }
+
+    /// Load the first 16 bytes of the block into an SSE register using an aligned load.
+    ///
+    /// # Panics
+    /// If the block is not full, i.e. its length is less than [`SimdBlock::size()`](`Alignment::size`).
+    #[cfg_attr(docsrs, doc(cfg(target_feature = "sse2")))]
+    #[cfg(target_feature = "sse2")]
+    #[must_use]
+    #[inline]
+    pub fn load_m128i(&self) -> __m128i {
+        self.assert_full();
+
+        // SAFETY:
+        // The block is aligned to `SimdBlock`, which is at least 16 bytes when `sse2` is enabled,
+        // and is full, so it contains at least 16 bytes.
+        unsafe { _mm_load_si128(self.as_ptr().cast()) }
+    }
+
+    /// Store an SSE register into the first 16 bytes of the block using an aligned store.
+    ///
+    /// Mutable blocks can be obtained with [`iter_blocks_mut`](`crate::AlignedSlice::iter_blocks_mut`).
+    ///
+    /// # Panics
+    /// If the block is not full, i.e. its length is less than [`SimdBlock::size()`](`Alignment::size`).
+    #[cfg_attr(docsrs, doc(cfg(target_feature = "sse2")))]
+    #[cfg(target_feature = "sse2")]
+    #[inline]
+    pub fn store_m128i(&mut self, value: __m128i) {
+        self.assert_full();
+
+        // SAFETY:
+        // Same as for `load_m128i`, and the block is borrowed mutably.
+        unsafe { _mm_store_si128(self.as_mut_ptr().cast(), value) }
+    }
+
+    /// Load the first 32 bytes of the block into an AVX register using an aligned load.
+    ///
+    /// # Panics
+    /// If the block is not full, i.e. its length is less than [`SimdBlock::size()`](`Alignment::size`).
+    #[cfg_attr(docsrs, doc(cfg(target_feature = "avx")))]
+    #[cfg(target_feature = "avx")]
+    #[must_use]
+    #[inline]
+    pub fn load_m256i(&self) -> __m256i {
+        self.assert_full();
+
+        // SAFETY:
+        // The block is aligned to `SimdBlock`, which is at least 32 bytes when `avx` is enabled,
+        // and is full, so it contains at least 32 bytes.
+        unsafe { _mm256_load_si256(self.as_ptr().cast()) }
+    }
+
+    /// Store an AVX register into the first 32 bytes of the block using an aligned store.
+    ///
+    /// Mutable blocks can be obtained with [`iter_blocks_mut`](`crate::AlignedSlice::iter_blocks_mut`).
+    ///
+    /// # Panics
+    /// If the block is not full, i.e. its length is less than [`SimdBlock::size()`](`Alignment::size`).
+    #[cfg_attr(docsrs, doc(cfg(target_feature = "avx")))]
+    #[cfg(target_feature = "avx")]
+    #[inline]
+    pub fn store_m256i(&mut self, value: __m256i) {
+        self.assert_full();
+
+        // SAFETY:
+        // Same as for `load_m256i`, and the block is borrowed mutably.
+        unsafe { _mm256_store_si256(self.as_mut_ptr().cast(), value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alignment::{Alignment, SimdBlock};
+    use crate::AlignedBytes;
+
+    #[test]
+    #[cfg(target_feature = "sse2")]
+    fn m128i_round_trips_through_block() {
+        let size = SimdBlock::size();
+        let source: AlignedBytes<SimdBlock> = AlignedBytes::new_initialize(size, |i| i as u8);
+        let mut target: AlignedBytes<SimdBlock> = AlignedBytes::new_zeroed(size);
+
+        let value = source.iter_blocks().next().unwrap().load_m128i();
+        target.iter_blocks_mut().next().unwrap().store_m128i(value);
+
+        assert_eq!(source[..16], target[..16]);
+    }
+
+    #[test]
+    #[cfg(target_feature = "avx")]
+    fn m256i_round_trips_through_block() {
+        let size = SimdBlock::size();
+        let source: AlignedBytes<SimdBlock> = AlignedBytes::new_initialize(size, |i| i as u8);
+        let mut target: AlignedBytes<SimdBlock> = AlignedBytes::new_zeroed(size);
+
+        let value = source.iter_blocks().next().unwrap().load_m256i();
+        target.iter_blocks_mut().next().unwrap().store_m256i(value);
+
+        assert_eq!(source[..32], target[..32]);
+    }
+
+    #[test]
+    #[cfg(target_feature = "sse2")]
+    #[should_panic(expected = "SIMD loads and stores require a full block")]
+    fn load_from_partial_block_panics() {
+        let bytes: AlignedBytes<SimdBlock> = AlignedBytes::new_zeroed(SimdBlock::size() - 1);
+
+        let _ = bytes.iter_blocks().next().unwrap().load_m128i();
+    }
+}
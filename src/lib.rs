@@ -133,6 +133,7 @@
 //!
 
 pub mod alignment;
+mod array;
 mod bytes;
 mod iterators;
 mod slice;
@@ -140,11 +141,14 @@ mod slice;
 #[cfg(test)]
 pub(crate) mod test;
 
+pub use array::*;
 pub use bytes::*;
 pub use iterators::*;
 pub use slice::*;
 
-/// Common trait for [`AlignedBytes`] for all different alignments.
+/// Common trait for aligned byte spans for all different alignments.
+///
+/// Implemented by [`AlignedSlice`], and thus usable with [`AlignedBytes`] through [`Deref`](`std::ops::Deref`).
 pub trait Aligned {
     /// Return the size of the alignment in bytes.
     fn alignment_size() -> usize;
@@ -153,8 +157,31 @@ pub trait Aligned {
     fn offset(&self, count: isize) -> &Self;
 }
 
-// TODO: Implement indexing?
-// TODO: Implement IntoIterator for AlignedBytes and an Iterator for AlignedSlice that iterates over aligned blocks.
+/// Check whether `ptr` is aligned to an `alignment` byte boundary.
+///
+/// Useful for validating pointers obtained from outside of this crate. The check is done on the
+/// remainder of the pointer's integer representation, since
+#[cfg_attr(docsrs, doc = "[`align_offset`](`pointer::align_offset`)")]
+#[cfg_attr(not(docsrs), doc = "`align_offset`")]
+/// is allowed to return false negatives.
+///
+/// # Panics
+/// If `alignment` is zero.
+///
+/// # Examples
+/// ```rust
+/// # use aligners::{is_aligned_to, AlignedBytes, alignment::{self, Alignment}};
+/// let bytes = AlignedBytes::<alignment::TwoTo<4>>::new_zeroed(32);
+///
+/// assert!(is_aligned_to(bytes.as_ptr(), 16));
+/// assert!(!is_aligned_to(bytes[1..].as_ptr(), 16));
+/// ```
+#[must_use]
+#[inline]
+pub fn is_aligned_to<T>(ptr: *const T, alignment: usize) -> bool {
+    assert_ne!(alignment, 0, "alignment must be non-zero");
+    (ptr as usize).is_multiple_of(alignment)
+}
 
 #[cfg(test)]
 mod tests {
@@ -163,9 +190,7 @@ mod tests {
     #[test]
     fn is_page_aligned_when_created_from_unaligned_slice() {
         let alignment_size = page_size::get();
-        let slice: &[u8] = &std::iter::repeat(42)
-            .take(alignment_size)
-            .collect::<Vec<_>>();
+        let slice: &[u8] = &std::iter::repeat_n(42, alignment_size).collect::<Vec<_>>();
         let misalignment = slice.as_ptr() as usize % alignment_size;
         let source = if misalignment > 0 { slice } else { &slice[1..] };
         let bytes = AlignedBytes::<alignment::Page>::from(source);
@@ -187,4 +212,121 @@ mod tests {
 
         assert_eq!(bytes.len(), 0);
     }
+
+    #[test]
+    fn is_huge_page_aligned_when_created_from_slice() {
+        let bytes = AlignedBytes::<alignment::HugePage2MiB>::from([1, 2, 3]);
+
+        test::assert_aligned(bytes.as_ptr(), 2 * 1024 * 1024);
+        assert_eq!(bytes, [1, 2, 3]);
+    }
+
+    #[test]
+    fn is_aligned_to_accepts_aligned_pointers() {
+        let bytes = AlignedBytes::<alignment::TwoTo<6>>::new_zeroed(128);
+
+        assert!(is_aligned_to(bytes.as_ptr(), 64));
+        assert!(is_aligned_to(bytes.as_ptr(), 1));
+        assert!(is_aligned_to(bytes[64..].as_ptr(), 64));
+        assert!(is_aligned_to(bytes.as_ptr().cast::<u64>(), 8));
+    }
+
+    #[test]
+    fn is_aligned_to_rejects_misaligned_pointers() {
+        let bytes = AlignedBytes::<alignment::TwoTo<6>>::new_zeroed(128);
+
+        assert!(!is_aligned_to(bytes[1..].as_ptr(), 2));
+        assert!(!is_aligned_to(bytes[32..].as_ptr(), 64));
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be non-zero")]
+    fn is_aligned_to_panics_on_zero_alignment() {
+        let _ = is_aligned_to(std::ptr::null::<u8>(), 0);
+    }
+
+    fn _assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+    // Compile-time checks that owned and borrowed aligned types can be shared across threads.
+    const _: () = {
+        let _ = _assert_send_sync::<AlignedBytes<alignment::Page>>;
+        let _ = _assert_send_sync::<AlignedVec<alignment::Page>>;
+        let _ = _assert_send_sync::<DynAlignedBytes>;
+        let _ = _assert_send_sync::<SecureAlignedBytes<alignment::Page>>;
+        let _ = _assert_send_sync::<IntoAlignedBytesIter<alignment::Page>>;
+        let _ = _assert_send_sync::<AlignedSlice<alignment::Page>>;
+        let _ = _assert_send_sync::<AlignedBlock<alignment::Twice<alignment::Page>>>;
+        let _ = _assert_send_sync::<AlignedArray<f32, alignment::Page>>;
+        let _ = _assert_send_sync::<AlignedTypedSlice<f32, alignment::Page>>;
+        #[cfg(all(feature = "libc", unix))]
+        let _ = _assert_send_sync::<crate::bytes::ReadOnlyAlignedBytes<alignment::Page>>;
+    };
+
+    #[test]
+    fn aligned_bytes_can_be_sent_to_another_thread() {
+        let bytes = AlignedBytes::<alignment::TwoTo<6>>::from([1, 2, 3]);
+
+        let sum = std::thread::spawn(move || bytes.iter().map(|&x| u32::from(x)).sum::<u32>())
+            .join()
+            .unwrap();
+
+        assert_eq!(6, sum);
+    }
+
+    #[test]
+    fn aligned_bytes_can_be_shared_between_threads() {
+        let bytes = AlignedBytes::<alignment::TwoTo<6>>::new_zeroed(128);
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    test::assert_aligned(bytes.as_ptr(), 64);
+                    assert!(bytes.iter().all(|&x| x == 0));
+                });
+            }
+        });
+    }
+
+    // Compile-time checks that the sizes of compile-time alignments are usable in const contexts.
+    const _: () = {
+        use alignment::ConstAlignment;
+        assert!(alignment::TwoTo::<5>::ALIGNMENT == 32);
+        assert!(alignment::Align::<256>::ALIGNMENT == 256);
+        assert!(alignment::Twice::<alignment::Eight>::ALIGNMENT == 16);
+        assert!(alignment::HugePage2MiB::ALIGNMENT == 2 * 1024 * 1024);
+    };
+
+    #[test]
+    fn const_alignment_sizes_are_usable_as_array_lengths() {
+        use alignment::ConstAlignment;
+        let two_to: [u8; alignment::TwoTo::<3>::ALIGNMENT] = Default::default();
+        let align: [u8; alignment::Align::<4>::ALIGNMENT] = Default::default();
+        let twice: [u8; alignment::Twice::<alignment::Twice<alignment::One>>::ALIGNMENT] =
+            Default::default();
+
+        assert_eq!(8, two_to.len());
+        assert_eq!(4, align.len());
+        assert_eq!(4, twice.len());
+    }
+
+    #[test]
+    fn const_alignment_is_usable_in_const_context() {
+        use alignment::ConstAlignment;
+        const SIZE: usize = alignment::Twice::<alignment::CacheLine>::ALIGNMENT;
+        let buffer = [0_u8; SIZE];
+
+        assert_eq!(128, buffer.len());
+        assert_eq!(
+            SIZE,
+            <alignment::Twice<alignment::CacheLine> as alignment::Alignment>::size()
+        );
+    }
+
+    #[test]
+    fn is_aligned_to_exact_size_with_align() {
+        let bytes = AlignedBytes::<alignment::Align<64>>::from([1, 2, 3]);
+
+        test::assert_aligned(bytes.as_ptr(), 64);
+        assert_eq!(bytes, [1, 2, 3]);
+    }
 }
@@ -1,15 +1,31 @@
 use crate::alignment::Alignment;
 use crate::bytes::AlignedBytes;
-use crate::iterators::AlignedBlockIterator;
+use crate::iterators::{
+    AlignedBlock, AlignedBlockExactIterator, AlignedBlockIterator, AlignedBlockIteratorMut,
+    AlignedBlockWindows, AlignedChunks,
+};
+use crate::Aligned;
 use std::borrow::{Borrow, BorrowMut};
 use std::mem;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 
 mod cmp;
 #[doc(inline)]
 #[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
 pub use cmp::*;
 
+mod bitwise;
+mod cursor;
+#[doc(inline)]
+#[allow(unreachable_pub, unused_imports)] // False positive, this is reachable and required.
+pub use cursor::*;
+
+mod hex;
+mod search;
+
+#[cfg(feature = "bytemuck")]
+mod pod;
+
 /// Slice of bytes aligned to a boundary represented by `A`.
 ///
 /// # Guarantees
@@ -30,15 +46,56 @@ pub struct AlignedSlice<A: Alignment> {
 }
 
 impl<A: Alignment> AlignedSlice<A> {
+    /// Form an aligned slice from a pointer and a length.
+    ///
+    /// This allows wrapping memory that is known to be aligned, for example obtained from an FFI call,
+    /// without copying. The alignment of `ptr` is checked with a [`debug_assert`],
+    /// so it is not checked in release builds.
+    ///
+    /// # Safety
+    /// - All the safety requirements of [`std::slice::from_raw_parts`] must be upheld
+    ///   for `ptr` and `len`, including that the lifetime `'a` is not longer than the
+    ///   memory remains valid and not mutated.
+    /// - `ptr` must be aligned to [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, AlignedSlice, alignment};
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    /// let slice: &AlignedSlice<alignment::Eight> = unsafe {
+    ///     AlignedSlice::from_raw_parts(bytes.as_ptr(), bytes.len())
+    /// };
+    ///
+    /// assert_eq!(slice, &[1, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn from_raw_parts<'a>(ptr: *const u8, len: usize) -> &'a Self {
+        debug_assert!(
+            (ptr as usize).is_multiple_of(A::size()),
+            "pointer passed to AlignedSlice::from_raw_parts is not aligned to {} bytes",
+            A::size()
+        );
+
+        let slice = std::slice::from_raw_parts(ptr, len);
+        mem::transmute::<&'a [u8], &'a Self>(slice)
+    }
+
     /// Returns the slice offset by `count` aligned blocks.
     /// This is equivalent to skipping `count * A::size()` bytes.
     ///
     /// # Panics
-    /// If there are less than `count` blocks until end of the slice.
+    /// If `count` is negative, since there is no data before the start of the slice,
+    /// or if there are less than `count` blocks until end of the slice.
     #[must_use]
     #[inline]
     pub fn offset(&self, count: isize) -> &Self {
-        let offset_in_bytes = A::size() * (count as usize);
+        if count < 0 {
+            panic!("cannot offset backwards, AlignedSlice has no data before its start (offset {count})")
+        }
+
+        // The cast is lossless, since `count` is non-negative.
+        let offset_in_bytes = A::size().saturating_mul(count as usize);
 
         if self.bytes.len() < offset_in_bytes {
             panic!(
@@ -53,216 +110,1803 @@ impl<A: Alignment> AlignedSlice<A> {
         unsafe { std::mem::transmute(&self[offset_in_bytes..]) }
     }
 
-    /// Return the size of the alignment in bytes.
+    /// Returns the slice offset by `count` aligned blocks, or `None` if that is out of range.
     ///
-    /// ## Note
-    /// This does not reflect the actual maximal alignment,
-    /// only the guarantee provided by `A`, which may be lower than
-    /// the actual alignment.
+    /// This is the non-panicking version of [`offset`](`AlignedSlice::offset`).
+    /// Offsetting exactly to the end of the slice yields an empty slice. A negative `count`
+    /// always yields `None`, since there is no data before the start of the slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(bytes.checked_offset(1).unwrap(), &[5, 6]);
+    /// assert!(bytes.checked_offset(3).is_none());
+    /// assert!(bytes.checked_offset(-1).is_none());
+    /// ```
     #[must_use]
-    #[inline(always)]
-    pub fn alignment_size(&self) -> usize {
-        A::size()
+    #[inline]
+    pub fn checked_offset(&self, count: isize) -> Option<&Self> {
+        let count = usize::try_from(count).ok()?;
+        let offset_in_bytes = A::size().checked_mul(count)?;
+
+        if self.bytes.len() < offset_in_bytes {
+            return None;
+        }
+
+        // SAFETY:
+        // Same as in `offset`.
+        Some(unsafe { mem::transmute::<&[u8], &Self>(&self.bytes[offset_in_bytes..]) })
     }
 
-    /// Return an iterator over consecutive aligned blocks of the slice.
+    /// Return the aligned subslice of the bytes in `range`.
+    ///
+    /// This works with any kind of range, as long as it starts at an alignment boundary.
+    /// Indexing with a range, as in `&slice[a..b]`, goes through [`Deref`] to `[u8]`
+    /// and always produces an unaligned `[u8]` instead.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is not a multiple of [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, AlignedSlice, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    /// let aligned: &AlignedSlice<alignment::Four> = bytes.aligned_range(4..6);
+    /// let unaligned: &[u8] = &bytes[1..3];
+    ///
+    /// assert_eq!(aligned, &[5, 6]);
+    /// assert_eq!(bytes.aligned_range(..=1), &[1, 2]);
+    /// assert_eq!(unaligned, &[2, 3]);
+    /// ```
     #[must_use]
     #[inline]
-    pub fn iter_blocks(&self) -> AlignedBlockIterator<A> {
-        AlignedBlockIterator::new(self)
+    pub fn aligned_range<R: RangeBounds<usize>>(&self, range: R) -> &Self {
+        let bounds = Self::aligned_range_bounds(&range);
+
+        // SAFETY:
+        // repr(transparent) and the start of the range is a multiple of A::size().
+        unsafe { mem::transmute::<&[u8], &Self>(&self.bytes[bounds]) }
     }
 
-    /// Relax the alignment to a smaller one.
+    /// Return the mutable aligned subslice of the bytes in `range`.
+    ///
+    /// Same as [`aligned_range`](`AlignedSlice::aligned_range`), but mutable.
     ///
     /// # Panics
-    /// If `B::size()` > `A::size()`.
+    /// If the range is out of bounds, or its start is not a multiple of [`A::size()`](`Alignment::size`).
     #[must_use]
     #[inline]
-    pub fn relax_alignment<B: Alignment>(&self) -> &AlignedSlice<B> {
-        if A::size() < B::size() {
-            panic!("target alignment is larger than source alignment, the 'relax_alignment' conversion is not valid")
-        }
+    pub fn aligned_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> &mut Self {
+        let bounds = Self::aligned_range_bounds(&range);
 
         // SAFETY:
-        // Since all alignments are multiples of two, A::size() >= B::size() => A::size() % B::size() == 0.
-        // The precedent condition is asserted above.
-        unsafe { mem::transmute(self) }
+        // repr(transparent) and the start of the range is a multiple of A::size().
+        unsafe { mem::transmute::<&mut [u8], &mut Self>(&mut self.bytes[bounds]) }
     }
-}
 
-impl<A: Alignment> AsRef<AlignedSlice<A>> for AlignedBytes<A> {
-    #[inline(always)]
-    fn as_ref(&self) -> &AlignedSlice<A> {
-        self
+    /// Divide the slice into two aligned slices at byte index `mid`.
+    ///
+    /// The first slice contains bytes `[0, mid)` and the second `[mid, len)`.
+    /// Since `mid` is required to be a multiple of [`A::size()`](`Alignment::size`),
+    /// both halves start at an alignment boundary.
+    ///
+    /// # Panics
+    /// If `mid` is not a multiple of [`A::size()`](`Alignment::size`), or if `mid > len`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    /// let (left, right) = bytes.split_at_aligned(4);
+    ///
+    /// assert_eq!(left, &[1, 2, 3, 4]);
+    /// assert_eq!(right, &[5, 6]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn split_at_aligned(&self, mid: usize) -> (&Self, &Self) {
+        Self::assert_aligned_split(mid);
+        let (left, right) = self.bytes.split_at(mid);
+
+        // SAFETY:
+        // - repr(transparent), the left half starts at the beginning of the slice,
+        // and the right one at a multiple of A::size() from the beginning, so both are aligned.
+        unsafe {
+            (
+                mem::transmute::<&[u8], &Self>(left),
+                mem::transmute::<&[u8], &Self>(right),
+            )
+        }
     }
-}
 
-impl<A: Alignment> AsMut<AlignedSlice<A>> for AlignedBytes<A> {
-    #[inline(always)]
-    fn as_mut(&mut self) -> &mut AlignedSlice<A> {
-        self
+    /// Divide the mutable slice into two mutable aligned slices at byte index `mid`.
+    ///
+    /// This is the mutable counterpart of [`split_at_aligned`](`AlignedSlice::split_at_aligned`).
+    ///
+    /// # Panics
+    /// If `mid` is not a multiple of [`A::size()`](`Alignment::size`), or if `mid > len`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let mut bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(6);
+    /// let (left, right) = bytes.split_at_mut_aligned(4);
+    /// left.fill(1);
+    /// right.fill(2);
+    ///
+    /// assert_eq!(bytes, [1, 1, 1, 1, 2, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn split_at_mut_aligned(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+        Self::assert_aligned_split(mid);
+        let (left, right) = self.bytes.split_at_mut(mid);
+
+        // SAFETY:
+        // - repr(transparent) and alignment are the same as in `split_at_aligned`,
+        // and the halves are disjoint, since they come from `split_at_mut`.
+        unsafe {
+            (
+                mem::transmute::<&mut [u8], &mut Self>(left),
+                mem::transmute::<&mut [u8], &mut Self>(right),
+            )
+        }
     }
-}
 
-impl<A: Alignment> AsRef<[u8]> for AlignedSlice<A> {
-    #[inline(always)]
-    fn as_ref(&self) -> &[u8] {
-        self
+    // Returns the range as bounds usable for indexing `[u8]`, asserting its start is aligned.
+    fn aligned_range_bounds<R: RangeBounds<usize>>(range: &R) -> (Bound<usize>, Bound<usize>) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+
+        match start {
+            Some(start) => assert!(
+                start.is_multiple_of(A::size()),
+                "range start {start} is not a multiple of the alignment size {}, \
+                 index the underlying [u8] for unaligned ranges",
+                A::size()
+            ),
+            None => panic!("range start overflows usize"),
+        }
+
+        (range.start_bound().cloned(), range.end_bound().cloned())
     }
-}
 
-impl<A: Alignment> AsMut<[u8]> for AlignedSlice<A> {
-    #[inline(always)]
-    fn as_mut(&mut self) -> &mut [u8] {
-        self
+    fn assert_aligned_split(mid: usize) {
+        assert!(
+            mid.is_multiple_of(A::size()),
+            "split index {mid} is not a multiple of the alignment size {}",
+            A::size()
+        );
     }
-}
 
-impl<A: Alignment> Borrow<AlignedSlice<A>> for AlignedBytes<A> {
-    #[inline(always)]
-    fn borrow(&self) -> &AlignedSlice<A> {
-        self
+    /// Check whether the bytes are actually aligned to [`A::size()`](`Alignment::size`).
+    ///
+    /// This is always `true` for correctly constructed values, and is meant for debug assertions
+    /// catching misuse of `unsafe` constructors. The check uses the integer value of the pointer,
+    /// since `pointer::align_offset` is allowed to give false negatives.
+    #[must_use]
+    #[inline]
+    pub fn verify_alignment(&self) -> bool {
+        crate::is_aligned_to(self.as_ptr(), A::size())
     }
-}
 
-impl<A: Alignment> BorrowMut<AlignedSlice<A>> for AlignedBytes<A> {
+    /// Return the size of the alignment in bytes.
+    ///
+    /// ## Note
+    /// This does not reflect the actual maximal alignment,
+    /// only the guarantee provided by `A`, which may be lower than
+    /// the actual alignment.
+    #[must_use]
     #[inline(always)]
-    fn borrow_mut(&mut self) -> &mut AlignedSlice<A> {
-        self
+    pub fn alignment_size(&self) -> usize {
+        A::size()
     }
-}
 
-impl<A: Alignment> Clone for AlignedBytes<A> {
+    /// Return an iterator over consecutive aligned blocks of the slice.
+    ///
+    /// The iterator is double-ended, so the blocks can be traversed from the end with
+    /// [`rev`](`Iterator::rev`). If the length of the slice is not a multiple of
+    /// [`A::size()`](`Alignment::size`), the short trailing block is then yielded first.
+    #[must_use]
     #[inline]
-    fn clone(&self) -> AlignedBytes<A> {
-        let slice: &AlignedSlice<A> = self;
-        slice.into()
+    pub fn iter_blocks(&self) -> AlignedBlockIterator<'_, A> {
+        AlignedBlockIterator::new(self)
     }
 
+    /// Return the `index`-th aligned block of the slice, or `None` if it is out of range.
+    ///
+    /// This is the same block as the one produced by [`iter_blocks`](`AlignedSlice::iter_blocks`)
+    /// at position `index`, but is obtained in constant time. In particular, the last block
+    /// may be shorter than [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(***bytes.get_block(0).unwrap(), [1, 2, 3, 4]);
+    /// assert_eq!(***bytes.get_block(1).unwrap(), [5, 6]);
+    /// assert!(bytes.get_block(2).is_none());
+    /// ```
+    #[must_use]
     #[inline]
-    fn clone_from(&mut self, other: &AlignedBytes<A>) {
-        let source: &AlignedSlice<A> = other;
-        let target: &mut AlignedSlice<A> = self;
+    pub fn get_block(&self, index: usize) -> Option<&AlignedBlock<A>> {
+        let start = index.checked_mul(A::size())?;
 
-        target.clone_from_slice(source);
-    }
-}
+        if start >= self.len() {
+            return None;
+        }
 
-impl<A: Alignment> Deref for AlignedBytes<A> {
-    type Target = AlignedSlice<A>;
+        let end = self.len().min(start + A::size());
 
-    #[inline]
-    fn deref(&self) -> &AlignedSlice<A> {
         // SAFETY:
-        // - the `data` pointer is a `NonNull` pointer to a single allocated object of size exactly `self.size`
-        //   and is properly aligned since proper alignment for `u8` is 1;
-        unsafe {
-            let slice = std::slice::from_raw_parts(self.as_ptr(), self.len());
-            std::mem::transmute(slice)
-        }
+        // `start` is a multiple of `A::size()`, so the block is aligned to `A`,
+        // and its size does not exceed `A::size()`.
+        Some(unsafe { mem::transmute::<&[u8], &AlignedBlock<A>>(&self.bytes[start..end]) })
     }
-}
 
-impl<A: Alignment> DerefMut for AlignedBytes<A> {
+    /// Return the first aligned block of the slice, or `None` if it is empty.
+    ///
+    /// The block spans up to the first [`A::size()`](`Alignment::size`) bytes.
+    #[must_use]
     #[inline]
-    fn deref_mut<'a>(&'a mut self) -> &'a mut AlignedSlice<A> {
-        // SAFETY:
-        // 1. All the conditions for from_raw_parts_mut:
-        //   > `data` must be valid for reads for `len * mem::size_of::<T>()` many bytes, and it must be properly aligned.
-        //   - `T` is `u8` and we allocated `len` bytes in AlignedBytes' ctors. Proper alignment for `u8` is 1, trivially satisfied.
-        //   > `data` must point to `len` consecutive properly initialized values of type `T`.
-        //   - This is upheld by AlignedBytes' constructors.
-        //   > The memory referenced by the returned slice must not be accessed through any other pointer
-        //   > (not derived from the return value) for the duration of lifetime `'a`. Both read and write accesses are forbidden.
-        //   - This follows from the explicit lifetimes given. To call deref_mut we mutably borrow the AlignedBytes for 'a,
-        //     and return a mutable borrow of a slice valid for 'a. Because of borrow rules, this can be the only valid mutable
-        //     reference to the underlying bytes.
-        //   > The total size len * mem::size_of::<T>() of the slice must be no larger than isize::MAX. See the safety documentation of pointer::offset.
-        //   - This is asserted in AlignedBytes' ctor.
-        // 2. transmute is safe because of AlignedSlice's repr(transparent).
-        unsafe {
-            let slice: &'a mut [u8] = std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len());
-            std::mem::transmute(slice)
-        }
+    pub fn first_block(&self) -> Option<&AlignedBlock<A>> {
+        self.get_block(0)
     }
-}
 
-impl<A: Alignment> Deref for AlignedSlice<A> {
-    type Target = [u8];
+    /// Return the last aligned block of the slice, or `None` if it is empty.
+    ///
+    /// The block is shorter than [`A::size()`](`Alignment::size`) if the length of the slice
+    /// is not a multiple of [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(bytes.first_block().unwrap(), &[1, 2, 3, 4]);
+    /// assert_eq!(bytes.last_block().unwrap(), &[5, 6]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn last_block(&self) -> Option<&AlignedBlock<A>> {
+        let blocks = self.len().div_ceil(A::size());
+        self.get_block(blocks.checked_sub(1)?)
+    }
 
+    /// Return the `index`-th aligned block of the slice.
+    ///
+    /// This is the panicking version of [`get_block`](`AlignedSlice::get_block`).
+    ///
+    /// # Panics
+    /// If `index` is not less than the number of blocks in the slice.
+    #[must_use]
     #[inline]
-    fn deref(&self) -> &[u8] {
-        // SAFETY:
-        // Using AlignedSlice's repr(transparent).
-        unsafe { std::mem::transmute(self) }
+    pub fn block(&self, index: usize) -> &AlignedBlock<A> {
+        match self.get_block(index) {
+            Some(block) => block,
+            None => panic!(
+                "block index {index} out of range for AlignedSlice of {} aligned blocks",
+                self.len().div_ceil(A::size())
+            ),
+        }
     }
-}
 
-impl<A: Alignment> DerefMut for AlignedSlice<A> {
+    /// Return an iterator over consecutive mutable aligned blocks of the slice.
+    #[must_use]
     #[inline]
-    fn deref_mut(&mut self) -> &mut [u8] {
-        // SAFETY:
-        // Using AlignedSlice's repr(transparent).
-        unsafe { std::mem::transmute(self) }
+    pub fn iter_blocks_mut(&mut self) -> AlignedBlockIteratorMut<'_, A> {
+        AlignedBlockIteratorMut::new(self)
     }
-}
 
-impl<A: Alignment> std::fmt::Debug for AlignedSlice<A> {
+    /// Return an iterator over overlapping windows of `blocks` consecutive aligned blocks of the slice.
+    ///
+    /// Each window is exactly `blocks * A::size()` bytes long, and consecutive windows
+    /// start one block apart, so every window is aligned. If the slice is shorter than
+    /// a single window, the iterator is empty.
+    ///
+    /// # Panics
+    /// If `blocks` is zero.
+    #[must_use]
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let deref: &[u8] = self;
-        std::fmt::Debug::fmt(deref, f)
+    pub fn windows_blocks(&self, blocks: usize) -> AlignedBlockWindows<'_, A> {
+        AlignedBlockWindows::new(self, blocks)
     }
-}
 
-impl<A: Alignment> Default for &AlignedSlice<A> {
+    /// Return an iterator over non-overlapping chunks of `chunk_size` bytes of the slice.
+    ///
+    /// This is the same as [`chunks`](`std::slice::[]::chunks`) on a byte slice. The chunks
+    /// are not [`AlignedSlices`](`AlignedSlice`), since they only start at an alignment
+    /// boundary if `chunk_size` is a multiple of [`A::size()`](`Alignment::size`). For aligned
+    /// chunks, use [`chunks_aligned`](`AlignedSlice::chunks_aligned`).
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
     #[inline]
-    fn default() -> Self {
-        let default_bytes: AlignedBytes<A> = Default::default();
-        // SAFETY:
-        // Using AlignedSlice's repr(transparent).
-        unsafe {
-            let slice = std::slice::from_raw_parts(default_bytes.as_ptr(), 0);
-            std::mem::transmute(slice)
+    pub fn chunks(&self, chunk_size: usize) -> std::slice::Chunks<'_, u8> {
+        if chunk_size == 0 {
+            panic!("chunk size must be non-zero");
         }
+
+        self.bytes.chunks(chunk_size)
     }
-}
 
-impl<A: Alignment> Default for &mut AlignedSlice<A> {
+    /// Return an iterator over non-overlapping chunks of `blocks` consecutive aligned blocks of the slice.
+    ///
+    /// Each chunk is `blocks * A::size()` bytes long, except possibly the last one,
+    /// which can be shorter. Every chunk starts at an alignment boundary.
+    ///
+    /// # Panics
+    /// If `blocks` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Two> = AlignedBytes::from([1, 2, 3, 4, 5]);
+    /// let mut chunks = bytes.chunks_aligned(2);
+    ///
+    /// assert_eq!(chunks.next().unwrap(), &[1, 2, 3, 4]);
+    /// assert_eq!(chunks.next().unwrap(), &[5]);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    #[must_use]
     #[inline]
-    fn default() -> Self {
-        let mut default_bytes: AlignedBytes<A> = Default::default();
-        // SAFETY:
-        // Using AlignedSlice's repr(transparent).
-        unsafe {
-            let slice = std::slice::from_raw_parts_mut(default_bytes.as_mut_ptr(), 0);
-            std::mem::transmute(slice)
-        }
+    pub fn chunks_aligned(&self, blocks: usize) -> AlignedChunks<'_, A> {
+        AlignedChunks::new(self, blocks)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::test::assert_aligned;
-    use crate::{alignment, AlignedBytes, AlignedSlice};
+    /// Return an iterator over consecutive full aligned blocks of the slice.
+    ///
+    /// If the length of the slice is not a multiple of [`A::size()`](`Alignment::size`),
+    /// the trailing bytes are not yielded and can be obtained from
+    /// [`AlignedBlockExactIterator::remainder`] instead.
+    #[must_use]
+    #[inline]
+    pub fn iter_blocks_exact(&self) -> AlignedBlockExactIterator<'_, A> {
+        AlignedBlockExactIterator::new(self)
+    }
 
-    #[test]
-    fn empty_slice_is_aligned() {
-        let empty: &AlignedSlice<alignment::Eight> = Default::default();
-        assert_aligned(empty.as_ptr(), 8);
+    /// Return the two raw pointers spanning the slice.
+    ///
+    /// The returned range is half-open, so the end pointer points one past the last byte.
+    /// The start pointer is aligned to [`A::size()`](`Alignment::size`). For an empty slice
+    /// both pointers are equal.
+    #[must_use]
+    #[inline]
+    pub fn as_ptr_range(&self) -> Range<*const u8> {
+        let slice: &[u8] = self;
+        slice.as_ptr_range()
     }
 
-    #[test]
-    fn empty_mut_slice_is_aligned() {
-        let empty: &mut AlignedSlice<alignment::Eight> = Default::default();
-        assert_aligned(empty.as_ptr(), 8);
+    /// Return the two unsafe mutable pointers spanning the slice.
+    ///
+    /// The returned range is half-open, so the end pointer points one past the last byte.
+    /// The start pointer is aligned to [`A::size()`](`Alignment::size`). For an empty slice
+    /// both pointers are equal.
+    #[must_use]
+    #[inline]
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut u8> {
+        let slice: &mut [u8] = self;
+        slice.as_mut_ptr_range()
     }
 
-    #[test]
+    /// Copy the bytes into a new [`Vec<u8>`].
+    ///
+    /// The alignment guarantee is not retained.
+    #[must_use]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let slice: &[u8] = self;
+        slice.to_vec()
+    }
+
+    /// Copy the bytes into a new [`AlignedBytes`] with the same alignment.
+    #[must_use]
+    #[inline]
+    pub fn to_aligned_bytes(&self) -> AlignedBytes<A> {
+        self.into()
+    }
+
+    /// Reinterpret the whole slice as a slice of `T`.
+    ///
+    /// This is [`<[u8]>::align_to`](`slice::align_to`) for the case where the alignment is known
+    /// to be sufficient, so there is never a prefix or a suffix.
+    /// For a safe alternative for plain-old-data types see `cast_slice` under the `bytemuck` feature.
+    ///
+    /// # Safety
+    /// Same as for [`<[u8]>::align_to`](`slice::align_to`) &ndash; the caller must ensure
+    /// that the bytes are a valid representation of `T` values.
+    ///
+    /// # Panics
+    /// If [`A::size()`](`Alignment::size`) is smaller than the alignment of `T`,
+    /// `T` is zero-sized, or the length of the slice is not a multiple of the size of `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(42u64.to_ne_bytes());
+    /// // SAFETY: every bit pattern is a valid u64.
+    /// let values: &[u64] = unsafe { bytes.as_typed_slice() };
+    ///
+    /// assert_eq!(values, [42]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn as_typed_slice<T>(&self) -> &[T] {
+        assert!(
+            A::size() >= mem::align_of::<T>(),
+            "alignment of {} bytes is insufficient for a type aligned to {} bytes",
+            A::size(),
+            mem::align_of::<T>()
+        );
+        assert!(
+            mem::size_of::<T>() != 0,
+            "zero-sized types are not supported"
+        );
+        assert!(
+            self.len().is_multiple_of(mem::size_of::<T>()),
+            "length {} is not a multiple of the type size {}",
+            self.len(),
+            mem::size_of::<T>()
+        );
+
+        // SAFETY:
+        // The pointer is aligned to A::size(), which is a power of two not smaller than align_of::<T>(),
+        // so also aligned for T. The length covers exactly the bytes of the slice, as asserted above.
+        // The validity of the values is guaranteed by the caller.
+        unsafe {
+            std::slice::from_raw_parts(self.as_ptr().cast::<T>(), self.len() / mem::size_of::<T>())
+        }
+    }
+
+    /// Fill the slice with `byte`.
+    ///
+    /// Equivalent to [`<[u8]>::fill`](`slice::fill`). The alignment may allow for a faster
+    /// implementation in the future.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(3);
+    /// bytes.fill(0xAB);
+    ///
+    /// assert_eq!(bytes, [0xAB, 0xAB, 0xAB]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, byte: u8) {
+        let slice: &mut [u8] = self;
+        slice.fill(byte);
+    }
+
+    /// Fill the slice with bytes returned by calling `f` repeatedly.
+    ///
+    /// Equivalent to [`<[u8]>::fill_with`](`slice::fill_with`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(3);
+    /// let mut next = 0;
+    /// bytes.fill_with(|| { next += 1; next });
+    ///
+    /// assert_eq!(bytes, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn fill_with<F: FnMut() -> u8>(&mut self, f: F) {
+        let slice: &mut [u8] = self;
+        slice.fill_with(f);
+    }
+
+    /// Copy bytes from the `src` range to the position starting at `dest`. The ranges may overlap.
+    ///
+    /// Equivalent to [`<[u8]>::copy_within`](`slice::copy_within`). The bytes can be moved
+    /// by an arbitrary offset; the alignment of the slice itself is not affected.
+    ///
+    /// # Panics
+    /// If `src` is out of bounds, its start is greater than its end,
+    /// or `dest + src.len()` is greater than the length of the slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5]);
+    /// bytes.copy_within(0..2, 3);
+    ///
+    /// assert_eq!(bytes, [1, 2, 3, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn copy_within(&mut self, src: Range<usize>, dest: usize) {
+        let slice: &mut [u8] = self;
+        slice.copy_within(src, dest);
+    }
+
+    /// Rotate the bytes in place so that the byte at `mid` becomes the first one.
+    ///
+    /// Equivalent to [`<[u8]>::rotate_left`](`slice::rotate_left`). Only the order of
+    /// bytes changes, the alignment of the slice is not affected.
+    ///
+    /// # Panics
+    /// If `mid` is greater than the length of the slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5]);
+    /// bytes.rotate_left(2);
+    ///
+    /// assert_eq!(bytes, [3, 4, 5, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn rotate_left(&mut self, mid: usize) {
+        let slice: &mut [u8] = self;
+        slice.rotate_left(mid);
+    }
+
+    /// Rotate the bytes in place so that the last `k` bytes become the first ones.
+    ///
+    /// Equivalent to [`<[u8]>::rotate_right`](`slice::rotate_right`). Only the order of
+    /// bytes changes, the alignment of the slice is not affected.
+    ///
+    /// # Panics
+    /// If `k` is greater than the length of the slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5]);
+    /// bytes.rotate_right(2);
+    ///
+    /// assert_eq!(bytes, [4, 5, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        let slice: &mut [u8] = self;
+        slice.rotate_right(k);
+    }
+
+    /// Reverse the order of bytes in place.
+    ///
+    /// Equivalent to [`<[u8]>::reverse`](`slice::reverse`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    /// bytes.reverse();
+    ///
+    /// assert_eq!(bytes, [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        let slice: &mut [u8] = self;
+        slice.reverse();
+    }
+
+    /// Swap two bytes in the slice.
+    ///
+    /// Equivalent to [`<[u8]>::swap`](`slice::swap`).
+    ///
+    /// # Panics
+    /// If `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    /// bytes.swap(0, 2);
+    ///
+    /// assert_eq!(bytes, [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let slice: &mut [u8] = self;
+        slice.swap(a, b);
+    }
+
+    /// View the bytes as a slice of native-endian [`u64`] words.
+    ///
+    /// # Panics
+    /// If [`A::size()`](`Alignment::size`) is less than the alignment of [`u64`], or the length of the slice is not a multiple of 8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(42u64.to_ne_bytes());
+    ///
+    /// assert_eq!(bytes.as_u64_slice(), [42]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_u64_slice(&self) -> &[u64] {
+        // SAFETY:
+        // Every bit pattern is a valid u64.
+        unsafe { self.as_typed_slice() }
+    }
+
+    /// View the bytes as a slice of native-endian [`u32`] words.
+    ///
+    /// # Panics
+    /// If [`A::size()`](`Alignment::size`) is less than the alignment of [`u32`], or the length of the slice is not a multiple of 4.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(42u32.to_ne_bytes());
+    ///
+    /// assert_eq!(bytes.as_u32_slice(), [42]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_u32_slice(&self) -> &[u32] {
+        // SAFETY:
+        // Every bit pattern is a valid u32.
+        unsafe { self.as_typed_slice() }
+    }
+
+    /// View the bytes as a slice of native-endian [`u16`] words.
+    ///
+    /// # Panics
+    /// If [`A::size()`](`Alignment::size`) is less than the alignment of [`u16`], or the length of the slice is not a multiple of 2.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(42u16.to_ne_bytes());
+    ///
+    /// assert_eq!(bytes.as_u16_slice(), [42]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_u16_slice(&self) -> &[u16] {
+        // SAFETY:
+        // Every bit pattern is a valid u16.
+        unsafe { self.as_typed_slice() }
+    }
+
+    /// Split the slice into consecutive `N`-byte arrays and a remainder shorter than `N`.
+    ///
+    /// This mirrors [`as_chunks`](`std::slice::[]::as_chunks`) on a byte slice, but requires `N`
+    /// to divide [`A::size()`](`Alignment::size`), so that every array is aligned to `N` bytes.
+    ///
+    /// # Panics
+    /// If `N` is zero or does not divide [`A::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+    /// let (chunks, remainder) = bytes.as_chunks::<4>();
+    ///
+    /// assert_eq!(chunks, [[1, 2, 3, 4]]);
+    /// assert_eq!(remainder, [5, 6]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[u8; N]], &[u8]) {
+        if N == 0 || !A::size().is_multiple_of(N) {
+            panic!(
+                "chunk size {N} does not divide the alignment size {}",
+                A::size()
+            );
+        }
+
+        let chunks_len = self.len() / N;
+        let (chunks, remainder) = self.bytes.split_at(chunks_len * N);
+
+        // SAFETY:
+        // `[u8; N]` has the same layout as `N` consecutive bytes and alignment 1,
+        // and `chunks` consists of exactly `chunks_len * N` initialized bytes.
+        let chunks =
+            unsafe { std::slice::from_raw_parts(chunks.as_ptr().cast::<[u8; N]>(), chunks_len) };
+
+        (chunks, remainder)
+    }
+
+    /// Overwrite all bytes with zeroes in a way that is guaranteed not to be optimised away.
+    ///
+    /// Useful for clearing sensitive data from a buffer that is going to be reused.
+    /// To zero the bytes automatically on drop use [`SecureAlignedBytes`](`crate::SecureAlignedBytes`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    /// bytes.zeroize();
+    ///
+    /// assert_eq!(bytes, [0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn zeroize(&mut self) {
+        for byte in self.iter_mut() {
+            // SAFETY:
+            // The pointer comes from a mutable reference, so it is valid, aligned, and exclusive.
+            unsafe { std::ptr::write_volatile(byte, 0) };
+        }
+
+        // Prevent the compiler from reordering later accesses before the volatile writes.
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Create new [`AlignedBytes`] with the same alignment by applying `f` to each byte.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 255]);
+    /// let mapped = bytes.map(|b| b.wrapping_add(1));
+    ///
+    /// assert_eq!(mapped, [2, 3, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map<F: FnMut(u8) -> u8>(&self, mut f: F) -> AlignedBytes<A> {
+        AlignedBytes::new_initialize(self.len(), |i| f(self[i]))
+    }
+
+    /// Relax the alignment to a smaller one.
+    ///
+    /// # Panics
+    /// If `B::size()` > `A::size()`.
+    #[must_use]
+    #[inline]
+    pub fn relax_alignment<B: Alignment>(&self) -> &AlignedSlice<B> {
+        if A::size() < B::size() {
+            panic!("target alignment is larger than source alignment, the 'relax_alignment' conversion is not valid")
+        }
+
+        // SAFETY:
+        // Since all alignments are multiples of two, A::size() >= B::size() => A::size() % B::size() == 0.
+        // The precedent condition is asserted above.
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Relax the alignment to a smaller one, or return `None` if `B::size()` > `A::size()`.
+    ///
+    /// This is the non-panicking version of [`relax_alignment`](`AlignedSlice::relax_alignment`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment};
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    ///
+    /// assert!(bytes.try_relax_alignment::<alignment::Four>().is_some());
+    /// assert!(bytes.try_relax_alignment::<alignment::TwoTo<4>>().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn try_relax_alignment<B: Alignment>(&self) -> Option<&AlignedSlice<B>> {
+        if A::size() < B::size() {
+            return None;
+        }
+
+        // SAFETY:
+        // Same as in `relax_alignment`, the condition is checked above.
+        Some(unsafe { mem::transmute::<&Self, &AlignedSlice<B>>(self) })
+    }
+
+    /// Tighten the alignment to a larger one, if the slice happens to satisfy it.
+    ///
+    /// Returns `Some` only if the slice is actually aligned to [`B::size()`](`Alignment::size`)
+    /// and its length is a multiple of [`B::size()`](`Alignment::size`). This is checked at runtime,
+    /// so it is the safe counterpart of [`relax_alignment`](`AlignedSlice::relax_alignment`).
+    /// If it fails, [`realign_to`](`AlignedSlice::realign_to`) can be used to copy
+    /// the bytes into a buffer with the stronger alignment.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, AlignedSlice, alignment};
+    /// let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::new_zeroed(128);
+    /// let slice: &AlignedSlice<alignment::Four> = bytes.relax_alignment();
+    ///
+    /// assert!(slice.tighten_alignment::<alignment::TwoTo<6>>().is_some());
+    /// assert!(slice.offset(1).tighten_alignment::<alignment::TwoTo<6>>().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn tighten_alignment<B: Alignment>(&self) -> Option<&AlignedSlice<B>> {
+        if !crate::is_aligned_to(self.as_ptr(), B::size()) || !self.len().is_multiple_of(B::size())
+        {
+            return None;
+        }
+
+        // SAFETY:
+        // repr(transparent) and the alignment to B::size() is checked above.
+        Some(unsafe { mem::transmute::<&Self, &AlignedSlice<B>>(self) })
+    }
+
+    /// Copy the bytes into a new [`AlignedBytes`] aligned to `B`.
+    ///
+    /// This works for any `B`, both stronger and weaker than `A`, at the cost of an allocation.
+    /// Note that the returned bytes have the same length, they are not padded to a multiple of
+    /// [`B::size()`](`Alignment::size`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use aligners::{AlignedBytes, alignment::{self, Alignment}};
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from([1, 2, 3]);
+    /// let realigned: AlignedBytes<alignment::Page> = bytes.realign_to();
+    ///
+    /// assert_eq!(realigned, [1, 2, 3]);
+    /// assert_eq!(realigned.as_ptr() as usize % alignment::Page::size(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn realign_to<B: Alignment>(&self) -> AlignedBytes<B> {
+        AlignedBytes::from(&self.bytes)
+    }
+}
+
+/// [`AlignedBytes`] can be used through its [`Deref`] to [`AlignedSlice`].
+impl<A: Alignment> Aligned for AlignedSlice<A> {
+    #[inline(always)]
+    fn alignment_size() -> usize {
+        A::size()
+    }
+
+    #[inline]
+    fn offset(&self, count: isize) -> &Self {
+        AlignedSlice::offset(self, count)
+    }
+}
+
+impl<A: Alignment> AsRef<AlignedSlice<A>> for AlignedBytes<A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &AlignedSlice<A> {
+        self
+    }
+}
+
+impl<A: Alignment> AsMut<AlignedSlice<A>> for AlignedBytes<A> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut AlignedSlice<A> {
+        self
+    }
+}
+
+impl<A: Alignment> AsRef<[u8]> for AlignedSlice<A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<A: Alignment> AsMut<[u8]> for AlignedSlice<A> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl<A: Alignment> Borrow<AlignedSlice<A>> for AlignedBytes<A> {
+    #[inline(always)]
+    fn borrow(&self) -> &AlignedSlice<A> {
+        self
+    }
+}
+
+impl<A: Alignment> BorrowMut<AlignedSlice<A>> for AlignedBytes<A> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut AlignedSlice<A> {
+        self
+    }
+}
+
+impl<A: Alignment> Clone for AlignedBytes<A> {
+    #[inline]
+    fn clone(&self) -> AlignedBytes<A> {
+        let slice: &AlignedSlice<A> = self;
+        slice.into()
+    }
+
+    #[inline]
+    fn clone_from(&mut self, other: &AlignedBytes<A>) {
+        let source: &AlignedSlice<A> = other;
+        source.clone_into(self);
+    }
+}
+
+impl<A: Alignment> ToOwned for AlignedSlice<A> {
+    type Owned = AlignedBytes<A>;
+
+    #[inline]
+    fn to_owned(&self) -> AlignedBytes<A> {
+        self.into()
+    }
+
+    /// Reuses the allocation of `target` if it has the same length, otherwise reallocates.
+    #[inline]
+    fn clone_into(&self, target: &mut AlignedBytes<A>) {
+        if target.len() == self.len() {
+            let target: &mut AlignedSlice<A> = target;
+            target.clone_from_slice(self);
+        } else {
+            *target = self.to_owned();
+        }
+    }
+}
+
+impl<A: Alignment> Deref for AlignedBytes<A> {
+    type Target = AlignedSlice<A>;
+
+    #[inline]
+    fn deref(&self) -> &AlignedSlice<A> {
+        // SAFETY:
+        // - the `data` pointer is a `NonNull` pointer to a single allocated object of size exactly `self.size`
+        //   and is properly aligned since proper alignment for `u8` is 1;
+        unsafe {
+            let slice = std::slice::from_raw_parts(self.as_ptr(), self.len());
+            std::mem::transmute(slice)
+        }
+    }
+}
+
+impl<A: Alignment> DerefMut for AlignedBytes<A> {
+    #[inline]
+    fn deref_mut<'a>(&'a mut self) -> &'a mut AlignedSlice<A> {
+        // SAFETY:
+        // 1. All the conditions for from_raw_parts_mut:
+        //   > `data` must be valid for reads for `len * mem::size_of::<T>()` many bytes, and it must be properly aligned.
+        //   - `T` is `u8` and we allocated `len` bytes in AlignedBytes' ctors. Proper alignment for `u8` is 1, trivially satisfied.
+        //   > `data` must point to `len` consecutive properly initialized values of type `T`.
+        //   - This is upheld by AlignedBytes' constructors.
+        //   > The memory referenced by the returned slice must not be accessed through any other pointer
+        //   > (not derived from the return value) for the duration of lifetime `'a`. Both read and write accesses are forbidden.
+        //   - This follows from the explicit lifetimes given. To call deref_mut we mutably borrow the AlignedBytes for 'a,
+        //     and return a mutable borrow of a slice valid for 'a. Because of borrow rules, this can be the only valid mutable
+        //     reference to the underlying bytes.
+        //   > The total size len * mem::size_of::<T>() of the slice must be no larger than isize::MAX. See the safety documentation of pointer::offset.
+        //   - This is asserted in AlignedBytes' ctor.
+        // 2. transmute is safe because of AlignedSlice's repr(transparent).
+        unsafe {
+            let slice: &'a mut [u8] = std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len());
+            std::mem::transmute(slice)
+        }
+    }
+}
+
+impl<A: Alignment> Deref for AlignedSlice<A> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<A: Alignment> DerefMut for AlignedSlice<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+// Number of bytes printed at each end of a truncated `Debug` output.
+const DEBUG_EDGE_LEN: usize = 16;
+
+/// Formats the bytes as a list, like a `[u8]`.
+///
+/// Buffers longer than 32 bytes are truncated to their first and last 16 bytes,
+/// with the total length printed in between.
+/// The alternate flag (`{:#?}`) prints all the bytes regardless of length.
+///
+/// # Examples
+/// ```rust
+/// # use aligners::{AlignedBytes, alignment};
+/// let bytes: AlignedBytes<alignment::Eight> = (0..100).collect();
+///
+/// assert_eq!(
+///     format!("{:?}", bytes),
+///     "[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, \
+///       … (100 bytes), \
+///       84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99]"
+/// );
+/// ```
+impl<A: Alignment> std::fmt::Debug for AlignedSlice<A> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deref: &[u8] = self;
+
+        if f.alternate() || deref.len() <= 2 * DEBUG_EDGE_LEN {
+            return std::fmt::Debug::fmt(deref, f);
+        }
+
+        let (head, rest) = deref.split_at(DEBUG_EDGE_LEN);
+        let tail = &rest[rest.len() - DEBUG_EDGE_LEN..];
+
+        f.debug_list()
+            .entries(head)
+            .entry(&format_args!("… ({} bytes)", deref.len()))
+            .entries(tail)
+            .finish()
+    }
+}
+
+impl<A: Alignment> Default for &AlignedSlice<A> {
+    #[inline]
+    fn default() -> Self {
+        let default_bytes: AlignedBytes<A> = Default::default();
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        unsafe {
+            let slice = std::slice::from_raw_parts(default_bytes.as_ptr(), 0);
+            std::mem::transmute(slice)
+        }
+    }
+}
+
+impl<A: Alignment> Default for &mut AlignedSlice<A> {
+    #[inline]
+    fn default() -> Self {
+        let mut default_bytes: AlignedBytes<A> = Default::default();
+        // SAFETY:
+        // Using AlignedSlice's repr(transparent).
+        unsafe {
+            let slice = std::slice::from_raw_parts_mut(default_bytes.as_mut_ptr(), 0);
+            std::mem::transmute(slice)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_aligned;
+    use crate::{alignment, AlignedBytes, AlignedSlice};
+    use std::ops::Bound;
+
+    #[test]
+    fn as_typed_slice_yields_u32_elements_without_prefix_or_suffix() {
+        let source = [1u32, 2, 3, u32::MAX];
+        let bytes: AlignedBytes<alignment::Four> =
+            source.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        // SAFETY: every bit pattern is a valid u32.
+        let values = unsafe { bytes.as_typed_slice::<u32>() };
+
+        assert_eq!(values, source);
+    }
+
+    #[test]
+    fn as_typed_slice_yields_u64_elements_without_prefix_or_suffix() {
+        let source = [1u64, 42, u64::MAX];
+        let bytes: AlignedBytes<alignment::TwoTo<5>> =
+            source.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        // SAFETY: every bit pattern is a valid u64.
+        let values = unsafe { bytes.as_typed_slice::<u64>() };
+
+        assert_eq!(values, source);
+    }
+
+    #[test]
+    #[should_panic(expected = "length 6 is not a multiple of the type size 4")]
+    fn as_typed_slice_with_length_mismatch_panics() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(6);
+
+        // SAFETY: every bit pattern is a valid u32.
+        let _ = unsafe { bytes.as_typed_slice::<u32>() };
+    }
+
+    #[test]
+    fn fill_sets_every_byte_of_block_aligned_buffer() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(100);
+
+        bytes.fill(0xAB);
+
+        assert_aligned(bytes.as_ptr(), 32);
+        assert!(bytes.iter().all(|&x| x == 0xAB));
+    }
+
+    #[test]
+    fn fill_with_calls_function_for_every_byte() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(100);
+        let mut calls = 0;
+
+        bytes.fill_with(|| {
+            calls += 1;
+            0xAB
+        });
+
+        assert_eq!(100, calls);
+        assert!(bytes.iter().all(|&x| x == 0xAB));
+    }
+
+    #[test]
+    fn copy_within_moves_overlapping_region_forward() {
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+
+        bytes.copy_within(0..4, 2);
+
+        assert_eq!(bytes, [1, 2, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn copy_within_moves_overlapping_region_backward() {
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+
+        bytes.copy_within(2..6, 0);
+
+        assert_eq!(bytes, [3, 4, 5, 6, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_within_out_of_bounds_panics() {
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5, 6]);
+
+        bytes.copy_within(2..6, 3);
+    }
+
+    #[test]
+    fn rotate_matches_vec_behaviour() {
+        let pattern = (0..37).collect::<Vec<u8>>();
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(&pattern);
+        let mut vec = pattern.clone();
+
+        bytes.rotate_left(10);
+        vec.rotate_left(10);
+        assert_eq!(bytes, vec);
+
+        bytes.rotate_right(23);
+        vec.rotate_right(23);
+        assert_eq!(bytes, vec);
+        assert_aligned(bytes.as_ptr(), 8);
+    }
+
+    #[test]
+    fn reverse_reverses_bytes() {
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4]);
+
+        bytes.reverse();
+
+        assert_eq!(bytes, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn swap_exchanges_two_bytes() {
+        let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4]);
+
+        bytes.swap(1, 3);
+
+        assert_eq!(bytes, [1, 4, 3, 2]);
+    }
+
+    #[test]
+    fn as_word_slices_reinterpret_known_pattern() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(16, |i| i as u8);
+
+        assert_eq!(
+            bytes.as_u64_slice(),
+            [
+                u64::from_ne_bytes([0, 1, 2, 3, 4, 5, 6, 7]),
+                u64::from_ne_bytes([8, 9, 10, 11, 12, 13, 14, 15])
+            ]
+        );
+        assert_eq!(bytes.as_u32_slice()[1], u32::from_ne_bytes([4, 5, 6, 7]));
+        assert_eq!(bytes.as_u16_slice()[7], u16::from_ne_bytes([14, 15]));
+    }
+
+    #[test]
+    #[should_panic(expected = "length 12 is not a multiple of the type size 8")]
+    fn as_u64_slice_with_non_multiple_length_panics() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(12);
+
+        let _ = bytes.as_u64_slice();
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment of 4 bytes is insufficient for a type aligned to 8 bytes")]
+    fn as_u64_slice_with_insufficient_alignment_panics() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(16);
+
+        let _ = bytes.as_u64_slice();
+    }
+
+    #[test]
+    fn map_produces_aligned_transformed_bytes() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(300, |i| i as u8);
+
+        let mapped = bytes.map(|b| b.wrapping_add(1));
+
+        assert_aligned(mapped.as_ptr(), 64);
+        assert_eq!(
+            mapped,
+            (0..300)
+                .map(|i| (i as u8).wrapping_add(1))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn aligned_trait_is_usable_generically() {
+        use crate::Aligned;
+
+        fn second_block<T: Aligned + ?Sized>(aligned: &T) -> (usize, &T) {
+            (T::alignment_size(), aligned.offset(1))
+        }
+
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(24, |i| i as u8);
+        let (size, offset) = second_block::<AlignedSlice<_>>(&bytes);
+
+        assert_eq!(8, size);
+        assert_eq!(offset, (8..24).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn split_at_aligned_on_block_boundary() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let (left, right) = bytes.split_at_aligned(32);
+
+        assert_eq!(**left, (0..32).collect::<Vec<u8>>());
+        assert_eq!(**right, (32..40).collect::<Vec<u8>>());
+        assert_aligned(left.as_ptr(), 16);
+        assert_aligned(right.as_ptr(), 16);
+    }
+
+    #[test]
+    fn split_at_aligned_at_the_ends() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..32).collect();
+
+        let (empty, all) = bytes.split_at_aligned(0);
+        assert!(empty.is_empty());
+        assert_eq!(all, &*bytes);
+
+        let (all, empty) = bytes.split_at_aligned(32);
+        assert_eq!(all, &*bytes);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "split index 20 is not a multiple of the alignment size 16")]
+    fn split_at_aligned_off_block_boundary_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.split_at_aligned(20);
+    }
+
+    #[test]
+    fn split_at_mut_aligned_writes_to_each_half() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(40);
+
+        let (left, right) = bytes.split_at_mut_aligned(16);
+        assert_aligned(left.as_ptr(), 16);
+        assert_aligned(right.as_ptr(), 16);
+        left.fill(0xAA);
+        right.fill(0x55);
+
+        assert!(bytes[..16].iter().all(|&x| x == 0xAA));
+        assert!(bytes[16..].iter().all(|&x| x == 0x55));
+    }
+
+    #[test]
+    #[should_panic(expected = "split index 8 is not a multiple of the alignment size 16")]
+    fn split_at_mut_aligned_off_block_boundary_panics() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(40);
+
+        let _ = bytes.split_at_mut_aligned(8);
+    }
+
+    #[test]
+    fn get_block_returns_blocks_in_constant_time() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let first = bytes.get_block(0).unwrap();
+        let middle = bytes.get_block(1).unwrap();
+        let last = bytes.get_block(2).unwrap();
+
+        assert_eq!(***first, (0..16).collect::<Vec<u8>>());
+        assert_eq!(***middle, (16..32).collect::<Vec<u8>>());
+        assert_eq!(***last, (32..40).collect::<Vec<u8>>());
+        assert_aligned(middle.as_ptr(), 16);
+        assert_aligned(last.as_ptr(), 16);
+    }
+
+    #[test]
+    fn get_block_agrees_with_iter_blocks() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        for (i, block) in bytes.iter_blocks().enumerate() {
+            assert_eq!(bytes.get_block(i).unwrap().as_ptr(), block.as_ptr());
+            assert_eq!(bytes.get_block(i).unwrap().len(), block.len());
+        }
+    }
+
+    #[test]
+    fn get_block_out_of_range_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..32).collect();
+        let empty: &AlignedSlice<alignment::TwoTo<4>> = Default::default();
+
+        assert!(bytes.get_block(2).is_none());
+        assert!(bytes.get_block(usize::MAX).is_none());
+        assert!(empty.get_block(0).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "block index 3 out of range for AlignedSlice of 3 aligned blocks")]
+    fn block_out_of_range_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.block(3);
+    }
+
+    #[test]
+    fn aligned_range_with_aligned_start_is_aligned() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let slice: &AlignedSlice<alignment::TwoTo<4>> = bytes.aligned_range(16..36);
+
+        assert_eq!(**slice, (16..36).collect::<Vec<u8>>());
+        assert_aligned(slice.as_ptr(), 16);
+    }
+
+    #[test]
+    fn aligned_range_accepts_all_range_types() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        assert_eq!(
+            **bytes.aligned_range(16..=20),
+            (16..=20).collect::<Vec<u8>>()
+        );
+        assert_eq!(**bytes.aligned_range(32..), (32..40).collect::<Vec<u8>>());
+        assert_eq!(**bytes.aligned_range(..5), (0..5).collect::<Vec<u8>>());
+        assert_eq!(**bytes.aligned_range(..=5), (0..=5).collect::<Vec<u8>>());
+        assert_eq!(**bytes.aligned_range(..), (0..40).collect::<Vec<u8>>());
+        assert_eq!(
+            **bytes.aligned_range((Bound::Excluded(15), Bound::Unbounded)),
+            (16..40).collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn aligned_range_mut_with_aligned_start_is_aligned() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<4>> = AlignedBytes::new_zeroed(40);
+
+        let slice: &mut AlignedSlice<alignment::TwoTo<4>> = bytes.aligned_range_mut(32..40);
+        assert_aligned(slice.as_ptr(), 16);
+        slice.fill(1);
+
+        assert!(bytes[..32].iter().all(|&x| x == 0));
+        assert!(bytes[32..].iter().all(|&x| x == 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "range start 8 is not a multiple of the alignment size 16")]
+    fn aligned_range_with_misaligned_start_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.aligned_range(8..16);
+    }
+
+    #[test]
+    #[should_panic(expected = "range start 8 is not a multiple of the alignment size 16")]
+    fn aligned_range_inclusive_with_misaligned_start_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.aligned_range(8..=16);
+    }
+
+    #[test]
+    fn index_with_any_range_yields_bytes() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let slice: &[u8] = &bytes[8..16];
+        let inclusive: &[u8] = &bytes[8..=16];
+
+        assert_eq!(slice, (8..16).collect::<Vec<u8>>());
+        assert_eq!(inclusive, (8..=16).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn try_relax_alignment_to_smaller_alignment() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let relaxed = bytes.try_relax_alignment::<alignment::Four>().unwrap();
+
+        assert_eq!(relaxed.as_ptr(), bytes.as_ptr());
+        assert_eq!(relaxed.len(), 40);
+        assert_eq!(4, relaxed.alignment_size());
+        assert!(bytes.try_relax_alignment::<alignment::TwoTo<4>>().is_some());
+    }
+
+    #[test]
+    fn try_relax_alignment_to_larger_alignment_is_none() {
+        let bytes: AlignedBytes<alignment::Four> = (0..40).collect();
+
+        assert!(bytes.try_relax_alignment::<alignment::Eight>().is_none());
+    }
+
+    #[test]
+    fn tighten_alignment_of_over_aligned_slice() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::new_zeroed(128);
+        let slice: &AlignedSlice<alignment::Four> = bytes.relax_alignment();
+
+        let tightened = slice.tighten_alignment::<alignment::TwoTo<6>>().unwrap();
+
+        assert_eq!(tightened.as_ptr(), bytes.as_ptr());
+        assert_eq!(tightened.len(), 128);
+        assert_eq!(64, tightened.alignment_size());
+    }
+
+    #[test]
+    fn tighten_alignment_of_misaligned_slice_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::new_zeroed(128);
+        let slice: &AlignedSlice<alignment::Four> = bytes.relax_alignment();
+
+        assert!(slice
+            .offset(1)
+            .tighten_alignment::<alignment::TwoTo<6>>()
+            .is_none());
+    }
+
+    #[test]
+    fn tighten_alignment_with_length_not_multiple_of_target_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> = AlignedBytes::new_zeroed(100);
+        let slice: &AlignedSlice<alignment::Four> = bytes.relax_alignment();
+
+        assert!(slice.tighten_alignment::<alignment::TwoTo<6>>().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn realign_to_simd_block_copies_with_stronger_alignment() {
+        use crate::alignment::{Alignment, SimdBlock};
+
+        let bytes: AlignedBytes<alignment::Four> = (0..=255).collect();
+
+        let realigned: AlignedBytes<SimdBlock> = bytes.realign_to();
+
+        assert_eq!(**realigned, **bytes);
+        assert_aligned(realigned.as_ptr(), SimdBlock::size());
+    }
+
+    #[test]
+    fn checked_offset_in_range() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let offset = bytes.checked_offset(2).unwrap();
+
+        assert_eq!(**offset, (32..40).collect::<Vec<u8>>());
+        assert_aligned(offset.as_ptr(), 16);
+    }
+
+    #[test]
+    fn checked_offset_exactly_at_end_is_empty() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..32).collect();
+
+        let offset = bytes.checked_offset(2).unwrap();
+
+        assert!(offset.is_empty());
+    }
+
+    #[test]
+    fn checked_offset_past_end_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        assert!(bytes.checked_offset(4).is_none());
+        assert!(bytes.checked_offset(isize::MAX).is_none());
+    }
+
+    #[test]
+    fn checked_offset_negative_is_none() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        assert!(bytes.checked_offset(-1).is_none());
+        assert!(bytes.checked_offset(isize::MIN).is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot offset backwards, AlignedSlice has no data before its start (offset -1)"
+    )]
+    fn offset_negative_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.offset(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset 4 out of range for AlignedSlice of 2 aligned blocks")]
+    fn offset_past_end_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let _ = bytes.offset(4);
+    }
+
+    #[test]
+    fn debug_of_short_buffer_prints_all_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = (0..32).collect();
+        let expected = format!("{:?}", (0..32).collect::<Vec<u8>>());
+
+        assert_eq!(expected, format!("{:?}", bytes));
+        assert_eq!(expected, format!("{:?}", &*bytes));
+    }
+
+    #[test]
+    fn debug_of_long_buffer_is_truncated() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(1024 * 1024);
+        let zeroes = ["0"; 16].join(", ");
+        let expected = format!("[{zeroes}, … (1048576 bytes), {zeroes}]");
+
+        assert_eq!(expected, format!("{:?}", bytes));
+        assert_eq!(expected, format!("{:?}", &*bytes));
+    }
+
+    #[test]
+    fn alternate_debug_of_long_buffer_prints_all_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = (0..100).collect();
+        let expected = format!("{:#?}", (0..100).collect::<Vec<u8>>());
+
+        assert_eq!(expected, format!("{:#?}", bytes));
+    }
+
+    #[test]
+    fn cow_of_borrowed_slice_into_owned() {
+        use std::borrow::Cow;
+
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..100).collect();
+        let slice: &AlignedSlice<alignment::TwoTo<5>> = &bytes;
+        let cow: Cow<'_, AlignedSlice<alignment::TwoTo<5>>> = Cow::Borrowed(slice);
+
+        let owned: AlignedBytes<alignment::TwoTo<5>> = cow.into_owned();
+
+        assert_eq!(owned, bytes);
+        assert_ne!(owned.as_ptr(), bytes.as_ptr());
+        assert_aligned(owned.as_ptr(), 32);
+    }
+
+    #[test]
+    fn cow_to_mut_copies_borrowed_slice() {
+        use std::borrow::Cow;
+
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..100).collect();
+        let mut cow: Cow<'_, AlignedSlice<alignment::TwoTo<5>>> = Cow::Borrowed(&bytes);
+
+        cow.to_mut()[0] = 42;
+
+        assert_eq!(0, bytes[0]);
+        assert_eq!(42, cow[0]);
+    }
+
+    #[test]
+    fn clone_into_reuses_allocation_of_same_length() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..100).collect();
+        let mut target: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(100);
+        let ptr = target.as_ptr();
+
+        bytes.clone_into(&mut target);
+
+        assert_eq!(target, bytes);
+        assert_eq!(ptr, target.as_ptr());
+    }
+
+    #[test]
+    fn clone_into_and_clone_from_with_different_length() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = (0..100).collect();
+        let mut target: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(3);
+        let mut other: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(300);
+
+        bytes.clone_into(&mut target);
+        other.clone_from(&bytes);
+
+        assert_eq!(target, bytes);
+        assert_eq!(other, bytes);
+        assert_aligned(target.as_ptr(), 32);
+    }
+
+    #[test]
+    fn chunks_of_arbitrary_size() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..10).collect();
+
+        let chunks = bytes.chunks(3).collect::<Vec<_>>();
+
+        assert_eq!(chunks, [&[0, 1, 2][..], &[3, 4, 5], &[6, 7, 8], &[9]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_of_zero_size_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..10).collect();
+
+        let _ = bytes.chunks(0);
+    }
+
+    #[test]
+    fn first_and_last_block_of_exact_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..48).collect();
+
+        let first = bytes.first_block().unwrap();
+        let last = bytes.last_block().unwrap();
+
+        assert_eq!(*first, (0..16).collect::<Vec<u8>>());
+        assert_eq!(*last, (32..48).collect::<Vec<u8>>());
+        assert_aligned(last.as_ptr(), 16);
+    }
+
+    #[test]
+    fn first_and_last_block_with_remainder() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..40).collect();
+
+        let first = bytes.first_block().unwrap();
+        let last = bytes.last_block().unwrap();
+
+        assert_eq!(*first, (0..16).collect::<Vec<u8>>());
+        assert_eq!(*last, (32..40).collect::<Vec<u8>>());
+        assert_aligned(last.as_ptr(), 16);
+    }
+
+    #[test]
+    fn first_and_last_block_of_single_short_block_are_the_same() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..5).collect();
+
+        assert_eq!(bytes.first_block(), bytes.last_block());
+        assert_eq!(5, bytes.last_block().unwrap().len());
+    }
+
+    #[test]
+    fn first_and_last_block_of_empty_slice_are_none() {
+        let empty: &AlignedSlice<alignment::TwoTo<4>> = Default::default();
+
+        assert!(empty.first_block().is_none());
+        assert!(empty.last_block().is_none());
+    }
+
+    #[test]
+    fn as_chunks_of_exact_multiple() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..48).collect();
+
+        let (chunks, remainder) = bytes.as_chunks::<16>();
+
+        assert_eq!(3, chunks.len());
+        assert!(remainder.is_empty());
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(
+                chunk.as_slice(),
+                (i * 16..(i + 1) * 16).map(|x| x as u8).collect::<Vec<_>>()
+            );
+            assert_aligned(chunk.as_ptr(), 16);
+        }
+    }
+
+    #[test]
+    fn as_chunks_with_remainder() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..21).collect();
+
+        let (chunks, remainder) = bytes.as_chunks::<8>();
+
+        assert_eq!(
+            chunks,
+            [[0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]]
+        );
+        assert_eq!(remainder, [16, 17, 18, 19, 20]);
+        assert_aligned(chunks[1].as_ptr(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size 32 does not divide the alignment size 16")]
+    fn as_chunks_larger_than_alignment_panics() {
+        let bytes: AlignedBytes<alignment::TwoTo<4>> = (0..64).collect();
+
+        let _ = bytes.as_chunks::<32>();
+    }
+
+    #[test]
+    fn verify_alignment_of_constructed_buffers() {
+        let bytes: AlignedBytes<alignment::TwoTo<7>> = AlignedBytes::new_zeroed(300);
+        let slice: &AlignedSlice<alignment::TwoTo<7>> = &bytes;
+        let empty: &AlignedSlice<alignment::TwoTo<7>> = Default::default();
+
+        assert!(bytes.verify_alignment());
+        assert!(slice.verify_alignment());
+        assert!(slice.offset(1).verify_alignment());
+        assert!(empty.verify_alignment());
+    }
+
+    #[test]
+    fn zeroize_clears_all_bytes() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(100, |i| i as u8 + 1);
+
+        bytes.zeroize();
+
+        assert!(bytes.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn empty_slice_is_aligned() {
+        let empty: &AlignedSlice<alignment::Eight> = Default::default();
+        assert_aligned(empty.as_ptr(), 8);
+    }
+
+    #[test]
+    fn empty_mut_slice_is_aligned() {
+        let empty: &mut AlignedSlice<alignment::Eight> = Default::default();
+        assert_aligned(empty.as_ptr(), 8);
+    }
+
+    #[test]
     fn alignment_size_equal_to_alignment_type() {
         let bytes: AlignedBytes<alignment::TwoTo<7>> = AlignedBytes::new_zeroed(1024);
         let slice: &AlignedSlice<alignment::TwoTo<7>> = &bytes;
 
         assert_eq!(128, slice.alignment_size());
     }
+
+    #[test]
+    fn to_vec_copies_bytes() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3]);
+        let slice: &AlignedSlice<alignment::TwoTo<5>> = &bytes;
+
+        assert_eq!(slice.to_vec(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn to_aligned_bytes_copies_into_aligned_allocation() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::from([1, 2, 3]);
+        let slice: &AlignedSlice<alignment::TwoTo<5>> = &bytes;
+        let copy = slice.to_aligned_bytes();
+
+        assert_eq!(copy, [1, 2, 3]);
+        assert_ne!(copy.as_ptr(), bytes.as_ptr());
+        assert_aligned(copy.as_ptr(), 32);
+    }
+
+    #[test]
+    fn ptr_range_spans_slice() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(100);
+        let range = bytes.as_ptr_range();
+
+        assert_eq!(100, range.end as usize - range.start as usize);
+        assert_aligned(range.start, 32);
+
+        let range = bytes.as_mut_ptr_range();
+
+        assert_eq!(100, range.end as usize - range.start as usize);
+        assert_aligned(range.start, 32);
+    }
+
+    #[test]
+    fn ptr_range_of_empty_slice() {
+        let empty: &AlignedSlice<alignment::Eight> = Default::default();
+        let range = empty.as_ptr_range();
+
+        assert_eq!(range.start, range.end);
+        assert_aligned(range.start, 8);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(
+        expected = "pointer passed to AlignedSlice::from_raw_parts is not aligned to 8 bytes"
+    )]
+    fn from_raw_parts_with_misaligned_pointer() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(16);
+
+        // SAFETY:
+        // The memory is valid, but the pointer is deliberately misaligned to trigger the assertion.
+        let _: &AlignedSlice<alignment::Eight> =
+            unsafe { AlignedSlice::from_raw_parts(bytes.as_ptr().add(1), 8) };
+    }
 }
@@ -0,0 +1,120 @@
+use super::AlignedSlice;
+use crate::alignment::Alignment;
+
+impl<A: Alignment> AlignedSlice<A> {
+    // Apply `op` to each pair of corresponding bytes, storing the result in `self`.
+    // The simple loop over two slices of equal length is reliably auto-vectorised.
+    #[inline(always)]
+    fn zip_with<F: Fn(u8, u8) -> u8>(&mut self, other: &AlignedSlice<A>, op: F) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "slices must have equal lengths for a bitwise operation"
+        );
+
+        for (byte, &other_byte) in self.iter_mut().zip(other.iter()) {
+            *byte = op(*byte, other_byte);
+        }
+    }
+
+    /// Apply bitwise XOR with `other` to each byte in place.
+    ///
+    /// # Panics
+    /// If the lengths of the slices differ.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1100, 0b1010]);
+    /// let mask: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1010, 0b1010]);
+    /// bytes.xor_with(&mask);
+    ///
+    /// assert_eq!(bytes, [0b0110, 0b0000]);
+    /// ```
+    #[inline]
+    pub fn xor_with(&mut self, other: &AlignedSlice<A>) {
+        self.zip_with(other, |a, b| a ^ b);
+    }
+
+    /// Apply bitwise AND with `other` to each byte in place.
+    ///
+    /// # Panics
+    /// If the lengths of the slices differ.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1100, 0b1010]);
+    /// let mask: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1010, 0b1010]);
+    /// bytes.and_with(&mask);
+    ///
+    /// assert_eq!(bytes, [0b1000, 0b1010]);
+    /// ```
+    #[inline]
+    pub fn and_with(&mut self, other: &AlignedSlice<A>) {
+        self.zip_with(other, |a, b| a & b);
+    }
+
+    /// Apply bitwise OR with `other` to each byte in place.
+    ///
+    /// # Panics
+    /// If the lengths of the slices differ.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let mut bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1100, 0b1010]);
+    /// let mask: AlignedBytes<alignment::Eight> = AlignedBytes::from([0b1010, 0b1010]);
+    /// bytes.or_with(&mask);
+    ///
+    /// assert_eq!(bytes, [0b1110, 0b1010]);
+    /// ```
+    #[inline]
+    pub fn or_with(&mut self, other: &AlignedSlice<A>) {
+        self.zip_with(other, |a, b| a | b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn xor_with_is_its_own_inverse() {
+        let original: AlignedBytes<alignment::TwoTo<5>> =
+            AlignedBytes::new_initialize(100, |i| i as u8);
+        let key: AlignedBytes<alignment::TwoTo<5>> =
+            AlignedBytes::new_initialize(100, |i| (i * 37) as u8);
+        let mut bytes = original.clone();
+
+        bytes.xor_with(&key);
+        assert_ne!(bytes, original);
+        bytes.xor_with(&key);
+
+        assert_eq!(bytes, original);
+    }
+
+    #[test]
+    fn and_with_and_or_with_apply_masks() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(40);
+        let ones: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_initialize(40, |_| 0xFF);
+
+        bytes.or_with(&ones);
+        assert!(bytes.iter().all(|&x| x == 0xFF));
+
+        bytes.and_with(&AlignedBytes::new_zeroed(40));
+        assert!(bytes.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "slices must have equal lengths for a bitwise operation")]
+    fn xor_with_mismatched_lengths_panics() {
+        let mut bytes: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(40);
+        let key: AlignedBytes<alignment::TwoTo<5>> = AlignedBytes::new_zeroed(39);
+
+        bytes.xor_with(&key);
+    }
+}
@@ -1,11 +1,58 @@
 use crate::alignment::Alignment;
 use crate::slice::AlignedSlice;
 
+impl<A: Alignment> AlignedSlice<A> {
+    /// Compare the bytes with `other` in constant time with respect to their contents.
+    ///
+    /// Unlike [`PartialEq`], this does not exit early on the first differing byte,
+    /// so the running time does not leak the position of the difference. This is required
+    /// when comparing secrets like MACs. The lengths of the slices are not considered secret,
+    /// and slices of different lengths are unequal immediately.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let tag: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    /// let expected: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3]);
+    ///
+    /// assert!(tag.ct_eq(&expected));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ct_eq(&self, other: &AlignedSlice<A>) -> bool {
+        const LANE: usize = std::mem::size_of::<usize>();
+
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut lanes = self.chunks_exact(LANE);
+        let mut other_lanes = other.chunks_exact(LANE);
+        let mut difference: usize = 0;
+
+        for (lane, other_lane) in (&mut lanes).zip(&mut other_lanes) {
+            let mut word = [0; LANE];
+            let mut other_word = [0; LANE];
+            word.copy_from_slice(lane);
+            other_word.copy_from_slice(other_lane);
+
+            difference |= usize::from_ne_bytes(word) ^ usize::from_ne_bytes(other_word);
+        }
+
+        for (byte, other_byte) in lanes.remainder().iter().zip(other_lanes.remainder()) {
+            difference |= usize::from(byte ^ other_byte);
+        }
+
+        // Hide the accumulator from the optimiser, so it cannot introduce an early exit.
+        std::hint::black_box(difference) == 0
+    }
+}
+
 impl<A: Alignment> PartialEq for AlignedSlice<A> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        let other_slice: &[u8] = other;
-        self.eq(other_slice)
+        <[u8]>::eq(self, other)
     }
 }
 
@@ -57,6 +104,34 @@ impl<A: Alignment, const N: usize> PartialEq<AlignedSlice<A>> for [u8; N] {
     }
 }
 
+impl<A: Alignment> PartialEq<str> for AlignedSlice<A> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.eq(other.as_bytes())
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedSlice<A>> for str {
+    #[inline]
+    fn eq(&self, other: &AlignedSlice<A>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<A: Alignment> PartialEq<&str> for AlignedSlice<A> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(other.as_bytes())
+    }
+}
+
+impl<A: Alignment> PartialEq<AlignedSlice<A>> for &str {
+    #[inline]
+    fn eq(&self, other: &AlignedSlice<A>) -> bool {
+        other.eq(self)
+    }
+}
+
 impl<A: Alignment> PartialOrd for AlignedSlice<A> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
@@ -67,9 +142,158 @@ impl<A: Alignment> PartialOrd for AlignedSlice<A> {
 impl<A: Alignment> Ord for AlignedSlice<A> {
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        <[u8]>::cmp(self, other)
+    }
+}
+
+impl<A: Alignment> std::hash::Hash for AlignedSlice<A> {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         let slice: &[u8] = self;
-        let other_slice: &[u8] = other;
+        std::hash::Hash::hash(slice, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes, AlignedSlice};
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn eq_on_large_equal_buffers() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(1 << 20, |i| (i * 7) as u8);
+        let other = bytes.clone();
+
+        assert_eq!(*bytes, *other);
+        assert_eq!(bytes.cmp(&other), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn eq_and_cmp_on_near_equal_buffers_agree_with_byte_slices() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(1000, |i| (i * 7) as u8);
+
+        for i in [0, 1, 15, 16, 17, 63, 64, 500, 991, 992, 999] {
+            for delta in [1, 255] {
+                let mut other = bytes.clone();
+                other[i] = other[i].wrapping_add(delta);
+                let slice: &AlignedSlice<alignment::TwoTo<6>> = &bytes;
+                let other_slice: &AlignedSlice<alignment::TwoTo<6>> = &other;
+                let expected = (**slice).cmp(&**other_slice);
+
+                assert_ne!(slice, other_slice);
+                assert_eq!(expected, slice.cmp(other_slice));
+                assert_eq!(expected.reverse(), other_slice.cmp(slice));
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_on_prefix_is_less() {
+        let bytes: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(100, |i| i as u8);
+        let prefix: AlignedBytes<alignment::TwoTo<6>> =
+            AlignedBytes::new_initialize(80, |i| i as u8);
+
+        assert_ne!(*bytes, *prefix);
+        assert_eq!(prefix.cmp(&bytes), std::cmp::Ordering::Less);
+        assert_eq!(bytes.cmp(&prefix), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn eq_and_cmp_with_small_alignment() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_initialize(37, |i| i as u8);
+        let mut other = bytes.clone();
+
+        assert_eq!(*bytes, *other);
+        other[20] = 0;
+        assert_ne!(*bytes, *other);
+        assert_eq!(bytes.cmp(&other), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn slice_is_equal_to_str_with_same_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from("abc");
+        let slice: &AlignedSlice<alignment::Eight> = &bytes;
+
+        assert_eq!(*slice, "abc");
+        assert_eq!("abc", *slice);
+        assert_eq!(slice, "abc");
+        assert_eq!("abc", slice);
+    }
+
+    #[test]
+    fn slice_is_not_equal_to_str_with_different_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from("abc");
+        let slice: &AlignedSlice<alignment::Eight> = &bytes;
+
+        assert_ne!(*slice, "abd");
+        assert_ne!(*slice, "ab");
+        assert_ne!("abcd", *slice);
+    }
+
+    #[test]
+    fn hash_of_two_equal_slices_is_equal() {
+        let bytes1: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(20, |i| i as u8);
+        let bytes2: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(20, |i| i as u8);
+        let slice1: &AlignedSlice<alignment::Eight> = &bytes1;
+        let slice2: &AlignedSlice<alignment::Eight> = &bytes2;
+
+        assert_eq!(hash_of(slice1), hash_of(slice2));
+    }
+
+    #[test]
+    fn hash_of_slice_is_equal_to_hash_of_owned_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(20, |i| i as u8);
+        let slice: &AlignedSlice<alignment::Eight> = &bytes;
+
+        assert_eq!(hash_of(&bytes), hash_of(slice));
+    }
+
+    #[test]
+    fn hash_of_block_is_equal_to_hash_of_slice() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(8, |i| i as u8);
+        let slice: &AlignedSlice<alignment::Eight> = &bytes;
+        let block = bytes.iter_blocks().next().unwrap();
+
+        assert_eq!(hash_of(block), hash_of(slice));
+    }
+
+    #[test]
+    fn ct_eq_on_equal_inputs_is_true() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(37, |i| i as u8);
+        let other: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(37, |i| i as u8);
+
+        assert!(bytes.ct_eq(&other));
+    }
+
+    #[test]
+    fn ct_eq_on_inputs_differing_in_a_lane_or_remainder_is_false() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(37, |i| i as u8);
+
+        for i in [0, 8, 36] {
+            let mut other = bytes.clone();
+            other[i] ^= 1;
+
+            assert!(!bytes.ct_eq(&other));
+        }
+    }
+
+    #[test]
+    fn ct_eq_on_different_lengths_is_false() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(16);
+        let other: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(15);
 
-        slice.cmp(other_slice)
+        assert!(!bytes.ct_eq(&other));
     }
 }
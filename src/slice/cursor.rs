@@ -0,0 +1,190 @@
+use super::AlignedSlice;
+use crate::alignment::Alignment;
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+use std::mem;
+
+/// Cursor over an [`AlignedSlice`] implementing [`Read`], [`BufRead`], and [`Seek`].
+///
+/// Unlike [`io::Cursor`], seeking past the end of the slice is an error.
+///
+/// # Examples
+/// ```rust
+/// use aligners::{alignment, AlignedBytes, AlignedCursor};
+/// use std::io::Read;
+///
+/// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([1, 2, 3, 4, 5]);
+/// let mut cursor = AlignedCursor::new(&bytes);
+/// let mut buf = [0; 2];
+///
+/// cursor.read_exact(&mut buf).unwrap();
+///
+/// assert_eq!(buf, [1, 2]);
+/// assert_eq!(cursor.position(), 2);
+/// ```
+#[derive(Debug)]
+pub struct AlignedCursor<'a, A: Alignment> {
+    bytes: &'a AlignedSlice<A>,
+    position: usize,
+}
+
+impl<'a, A: Alignment> AlignedCursor<'a, A> {
+    /// Create a new cursor positioned at the start of `bytes`.
+    #[must_use]
+    #[inline]
+    pub fn new(bytes: &'a AlignedSlice<A>) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Return the current position of the cursor. Guaranteed to be at most the length of the slice.
+    #[must_use]
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the underlying slice.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &'a AlignedSlice<A> {
+        self.bytes
+    }
+
+    /// Return the bytes from the current position to the end.
+    #[must_use]
+    #[inline]
+    pub fn remaining_slice(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Return the bytes from the current position to the end with the alignment guarantee,
+    /// if the position is at an [`A::size()`](`Alignment::size`) boundary.
+    ///
+    /// This allows zero-copy consumers requiring alignment to read directly from the cursor.
+    #[must_use]
+    #[inline]
+    pub fn remaining_aligned(&self) -> Option<&'a AlignedSlice<A>> {
+        if !self.position.is_multiple_of(A::size()) {
+            return None;
+        }
+
+        // SAFETY:
+        // The position is a multiple of A::size() and the slice is aligned to A::size(),
+        // so the suffix is also aligned.
+        unsafe {
+            Some(mem::transmute::<&[u8], &AlignedSlice<A>>(
+                self.remaining_slice(),
+            ))
+        }
+    }
+}
+
+impl<A: Alignment> Read for AlignedCursor<'_, A> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining_slice();
+        let count = std::cmp::min(buf.len(), remaining.len());
+
+        buf[..count].copy_from_slice(&remaining[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+impl<A: Alignment> BufRead for AlignedCursor<'_, A> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.position = std::cmp::min(self.position.saturating_add(amt), self.bytes.len());
+    }
+}
+
+impl<A: Alignment> Seek for AlignedCursor<'_, A> {
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => (n, 0),
+            SeekFrom::End(n) => (self.bytes.len() as u64, n),
+            SeekFrom::Current(n) => (self.position as u64, n),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(position) if position <= self.bytes.len() as u64 => {
+                // Cast is lossless, since the position is bounded by the slice length.
+                self.position = position as usize;
+                Ok(position)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or out of bounds position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlignedCursor;
+    use crate::{alignment, AlignedBytes};
+    use std::io::{BufRead, Read, Seek, SeekFrom};
+
+    #[test]
+    fn reading_in_small_chunks_yields_all_bytes() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(10, |i| i as u8);
+        let mut cursor = AlignedCursor::new(&bytes);
+        let mut result = vec![];
+        let mut buf = [0; 3];
+
+        loop {
+            let count = cursor.read(&mut buf).unwrap();
+            if count == 0 {
+                break;
+            }
+            result.extend_from_slice(&buf[..count]);
+        }
+
+        assert_eq!(bytes, result);
+    }
+
+    #[test]
+    fn seek_from_start_end_and_current() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(10, |i| i as u8);
+        let mut cursor = AlignedCursor::new(&bytes);
+
+        assert_eq!(4, cursor.seek(SeekFrom::Start(4)).unwrap());
+        assert_eq!(6, cursor.seek(SeekFrom::Current(2)).unwrap());
+        assert_eq!(5, cursor.seek(SeekFrom::Current(-1)).unwrap());
+        assert_eq!(7, cursor.seek(SeekFrom::End(-3)).unwrap());
+        assert_eq!(cursor.fill_buf().unwrap(), [7, 8, 9]);
+    }
+
+    #[test]
+    fn seek_out_of_bounds_is_an_error() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_zeroed(10);
+        let mut cursor = AlignedCursor::new(&bytes);
+        cursor.seek(SeekFrom::Start(5)).unwrap();
+
+        assert!(cursor.seek(SeekFrom::Start(11)).is_err());
+        assert!(cursor.seek(SeekFrom::End(1)).is_err());
+        assert!(cursor.seek(SeekFrom::Current(-6)).is_err());
+        assert_eq!(5, cursor.position());
+    }
+
+    #[test]
+    fn remaining_aligned_only_at_alignment_boundary() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::new_initialize(20, |i| i as u8);
+        let mut cursor = AlignedCursor::new(&bytes);
+
+        cursor.consume(3);
+        assert!(cursor.remaining_aligned().is_none());
+
+        cursor.consume(5);
+        let aligned = cursor.remaining_aligned().unwrap();
+        assert_eq!(aligned[0], 8);
+        crate::test::assert_aligned(aligned.as_ptr(), 8);
+    }
+}
@@ -0,0 +1,77 @@
+use super::AlignedSlice;
+use crate::alignment::Alignment;
+use crate::bytes::AlignedBytes;
+use std::fmt;
+
+// Write all bytes as a contiguous string of two-digit hex numbers, prefixed with "0x" if alternate.
+fn fmt_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+
+    for byte in bytes {
+        if upper {
+            write!(f, "{:02X}", byte)?;
+        } else {
+            write!(f, "{:02x}", byte)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<A: Alignment> fmt::LowerHex for AlignedSlice<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self, f, false)
+    }
+}
+
+impl<A: Alignment> fmt::UpperHex for AlignedSlice<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self, f, true)
+    }
+}
+
+impl<A: Alignment> fmt::LowerHex for AlignedBytes<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self, f, false)
+    }
+}
+
+impl<A: Alignment> fmt::UpperHex for AlignedBytes<A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self, f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes, AlignedSlice};
+
+    #[test]
+    fn lower_hex_is_contiguous() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0xDE, 0xAD]);
+
+        assert_eq!("dead", format!("{:x}", bytes));
+    }
+
+    #[test]
+    fn upper_hex_is_contiguous() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0xDE, 0xAD]);
+
+        assert_eq!("DEAD", format!("{:X}", bytes));
+    }
+
+    #[test]
+    fn alternate_hex_is_prefixed_and_zero_padded() {
+        let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from([0x0B, 0xAD]);
+        let slice: &AlignedSlice<alignment::Eight> = &bytes;
+
+        assert_eq!("0x0bad", format!("{:#x}", slice));
+        assert_eq!("0x0BAD", format!("{:#X}", slice));
+    }
+}
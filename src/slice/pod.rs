@@ -0,0 +1,83 @@
+use super::AlignedSlice;
+use crate::alignment::Alignment;
+use bytemuck::Pod;
+use std::mem;
+
+impl<A: Alignment> AlignedSlice<A> {
+    /// Reinterpret the bytes as a slice of plain-old-data values of type `T`.
+    ///
+    /// Unlike [`bytemuck::cast_slice`] on a regular byte slice, this cannot fail due to
+    /// misalignment at runtime as long as `A` is at least as strict as the alignment of `T`.
+    ///
+    /// # Panics
+    /// If [`A::size()`](`Alignment::size`) is smaller than the alignment of `T`,
+    /// or the length of the slice is not a multiple of the size of `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Four> = AlignedBytes::from(1u32.to_ne_bytes());
+    /// let values: &[u32] = bytes.cast_slice();
+    ///
+    /// assert_eq!(values, [1]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    #[must_use]
+    #[inline]
+    pub fn cast_slice<T: Pod>(&self) -> &[T] {
+        assert!(
+            A::size() >= mem::align_of::<T>(),
+            "alignment of {} bytes is insufficient for a type aligned to {} bytes",
+            A::size(),
+            mem::align_of::<T>()
+        );
+        assert!(
+            self.len().is_multiple_of(mem::size_of::<T>()),
+            "length {} is not a multiple of the type size {}",
+            self.len(),
+            mem::size_of::<T>()
+        );
+
+        bytemuck::cast_slice(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes};
+
+    #[test]
+    fn cast_to_u32_reads_native_endian_values() {
+        let source = [1u32, 2, 3, u32::MAX];
+        let bytes: AlignedBytes<alignment::Four> =
+            source.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        assert_eq!(bytes.cast_slice::<u32>(), source);
+    }
+
+    #[test]
+    fn cast_to_u64_reads_native_endian_values() {
+        let source = [1u64, 42, u64::MAX];
+        let bytes: AlignedBytes<alignment::Eight> =
+            source.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        assert_eq!(bytes.cast_slice::<u64>(), source);
+    }
+
+    #[test]
+    #[should_panic(expected = "length 7 is not a multiple of the type size 4")]
+    fn cast_with_length_mismatch_panics() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(7);
+
+        let _ = bytes.cast_slice::<u32>();
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment of 4 bytes is insufficient for a type aligned to 8 bytes")]
+    fn cast_with_insufficient_alignment_panics() {
+        let bytes: AlignedBytes<alignment::Four> = AlignedBytes::new_zeroed(16);
+
+        let _ = bytes.cast_slice::<u64>();
+    }
+}
@@ -0,0 +1,113 @@
+use super::AlignedSlice;
+use crate::alignment::Alignment;
+
+impl<A: Alignment> AlignedSlice<A> {
+    /// Return the index of the first occurrence of `needle`, or [`None`] if it does not occur.
+    ///
+    /// With the `memchr` feature enabled this uses the vectorised `memchr::memchr`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(b"hello");
+    ///
+    /// assert_eq!(Some(2), bytes.find_byte(b'l'));
+    /// assert_eq!(None, bytes.find_byte(b'x'));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn find_byte(&self, needle: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        {
+            memchr::memchr(needle, self)
+        }
+        #[cfg(not(feature = "memchr"))]
+        {
+            self.iter().position(|&b| b == needle)
+        }
+    }
+
+    /// Return whether `needle` occurs in the slice.
+    ///
+    /// With the `memchr` feature enabled this uses the vectorised `memchr::memchr`.
+    #[must_use]
+    #[inline]
+    pub fn contains_byte(&self, needle: u8) -> bool {
+        self.find_byte(needle).is_some()
+    }
+
+    /// Count the occurrences of `needle` in the slice.
+    ///
+    /// With the `bytecount` feature enabled this uses the SIMD-accelerated `bytecount::count`.
+    /// Otherwise it uses a scalar loop, which the compiler is usually able to auto-vectorise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aligners::{alignment, AlignedBytes};
+    ///
+    /// let bytes: AlignedBytes<alignment::Eight> = AlignedBytes::from(b"hello");
+    ///
+    /// assert_eq!(2, bytes.count_byte(b'l'));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn count_byte(&self, needle: u8) -> usize {
+        #[cfg(feature = "bytecount")]
+        {
+            bytecount::count(self, needle)
+        }
+        #[cfg(not(feature = "bytecount"))]
+        {
+            self.iter().filter(|&&b| b == needle).count()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alignment, AlignedBytes};
+
+    fn bytes() -> AlignedBytes<alignment::TwoTo<5>> {
+        AlignedBytes::new_initialize(100, |i| (i % 50) as u8 + 1)
+    }
+
+    #[test]
+    fn find_present_byte() {
+        assert_eq!(Some(9), bytes().find_byte(10));
+        assert!(bytes().contains_byte(10));
+    }
+
+    #[test]
+    fn find_absent_byte() {
+        assert_eq!(None, bytes().find_byte(0));
+        assert!(!bytes().contains_byte(0));
+    }
+
+    #[test]
+    fn find_byte_at_first_position() {
+        assert_eq!(Some(0), bytes().find_byte(1));
+    }
+
+    #[test]
+    fn find_byte_at_last_position() {
+        let mut bytes = bytes();
+        bytes[99] = 0;
+
+        assert_eq!(Some(99), bytes.find_byte(0));
+    }
+
+    #[test]
+    fn count_byte_with_known_count() {
+        assert_eq!(2, bytes().count_byte(10));
+        assert_eq!(0, bytes().count_byte(0));
+    }
+
+    #[test]
+    fn count_byte_in_long_buffer() {
+        let bytes: AlignedBytes<alignment::TwoTo<5>> =
+            AlignedBytes::new_initialize(10000, |i| if i % 7 == 0 { 42 } else { 0 });
+
+        assert_eq!(1429, bytes.count_byte(42));
+    }
+}
@@ -11,6 +11,14 @@ mod tests {
         verify.unwrap();
     }
 
+    #[test]
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    pub fn simd_alignment_on_wasm_is_16() {
+        use aligners::alignment::{Alignment, SimdBlock};
+
+        assert_eq!(16, SimdBlock::size());
+    }
+
     pub fn verify_simd_block_size() -> Result<()> {
         use aligners::alignment::{Alignment, SimdBlock};
         let expected_size: usize = std::env::var(EXPECTED_SWITCH_ENV_NAME)?.parse()?;
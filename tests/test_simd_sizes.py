@@ -3,8 +3,9 @@ import os
 
 
 class TestCase:
-    def __init__(self, feature, target_triple, toolchain, size):
+    def __init__(self, feature, target_triple, toolchain, size, crate_features=""):
         self.feature = feature
+        self.crate_features = crate_features
         self.target_triple = target_triple
         self.toolchain = toolchain
         self.size = size
@@ -28,19 +29,22 @@ matrix = [
     TestCase("sse4.1", x86, "stable", 16),
     TestCase("sse4.2", x86, "stable", 16),
     TestCase("ssse3", x86, "stable", 16),
-    TestCase("avx512f", x86, "nightly", 64),
+    TestCase("avx512f", x86, "nightly", 32),
+    TestCase("avx512f", x86, "nightly", 64, "avx512"),
 ]
 
 for test_case in matrix:
     print(f"TEST CASE: target_feature {test_case.feature} \
+with crate features '{test_case.crate_features}' \
 should cause SimdBlock to have size {test_case.size} \
 when compiled with {test_case.toolchain} \
 on {test_case.target_triple}")
     rustflags = f"-C target-feature=+{test_case.feature}"
     testflags = str(test_case.size)
     rustup = f"rustup override set {test_case.toolchain}"
-    build = f"cargo build --target {test_case.target_triple}"
-    test = f"cargo test simd_alignment_test --target {test_case.target_triple} -- --include-ignored"
+    features = f"--features {test_case.crate_features}" if test_case.crate_features else ""
+    build = f"cargo build --target {test_case.target_triple} {features}"
+    test = f"cargo test simd_alignment_test --target {test_case.target_triple} {features} -- --include-ignored"
 
     system_succeed(rustup)
     system_succeed(f"RUSTFLAGS=\"{rustflags}\" {build}")